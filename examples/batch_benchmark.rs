@@ -0,0 +1,41 @@
+//! Temps d'une epoch de régression (5875 enregistrements) selon la taille de batch
+//!
+//! `cargo run --release --example batch_benchmark`
+
+use std::time::Instant;
+use model_compilator::data::data_loader::ParkinsonDataset;
+use model_compilator::neural_network::{Activation, NeuralNetwork, NormalizationKind};
+
+const EPOCHS: usize = 5;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let dataset = ParkinsonDataset::load_all_data()?;
+    let mut timings = Vec::new();
+
+    for batch_norm in [false, true] {
+        for batch_size in [16, 64, 256] {
+            let mut network = NeuralNetwork::new(0.001);
+            network.set_seed(1);
+            for (input_size, output_size) in [(16, 128), (128, 64), (64, 32)] {
+                network.add_layer(input_size, output_size, Activation::Relu);
+                if batch_norm {
+                    network.add_normalization(NormalizationKind::batch());
+                }
+            }
+            network.add_layer(32, 1, Activation::Linear);
+
+            let start = Instant::now();
+            network.train_fast(&dataset.regression_inputs, &dataset.regression_targets, EPOCHS, batch_size);
+            let per_epoch = start.elapsed().as_secs_f64() * 1000.0 / EPOCHS as f64;
+            timings.push((batch_norm, batch_size, per_epoch));
+        }
+    }
+
+    println!("\n⏱️  16→128→64→32→1, {} enregistrements:", dataset.regression_inputs.len());
+    for (batch_norm, batch_size, per_epoch) in timings {
+        let label = if batch_norm { "BatchNorm" } else { "sans norm." };
+        println!("   {:<10}  batch {:>3}: {:6.1} ms/epoch", label, batch_size, per_epoch);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,212 @@
+use ndarray::Array1;
+use serde::{Deserialize, Serialize};
+use super::data_loader::{CLASSIFICATION_FEATURES, REGRESSION_FEATURES};
+use super::preprocessing::MinMaxScaler;
+
+/// Mesure canonique et son nom dans chaque fichier
+#[derive(Debug, Clone, Copy)]
+pub struct AlignedFeature {
+    pub name: &'static str,
+    pub classification: Option<&'static str>,
+    pub regression: Option<&'static str>,
+    /// Les deux colonnes ne mesurent pas exactement la même grandeur (quotients de
+    /// perturbation MDVP:PPQ et MDVP:APQ face à Jitter:PPQ5 et Shimmer:APQ11)
+    pub approximate: bool,
+}
+
+impl AlignedFeature {
+    const fn shared(name: &'static str, classification: &'static str, regression: &'static str) -> Self {
+        Self { name, classification: Some(classification), regression: Some(regression), approximate: false }
+    }
+
+    const fn approximate(name: &'static str, classification: &'static str, regression: &'static str) -> Self {
+        Self { name, classification: Some(classification), regression: Some(regression), approximate: true }
+    }
+
+    const fn classification_only(name: &'static str, classification: &'static str) -> Self {
+        Self { name, classification: Some(classification), regression: None, approximate: false }
+    }
+}
+
+/// Mesures canoniques et leur nom dans chaque fichier
+///
+/// Les 16 premières existent dans les deux datasets (dont 2 par une mesure seulement
+/// approchée), les 6 dernières n'existent que dans le dataset de classification.
+pub const ALIGNED_FEATURES: [AlignedFeature; 22] = [
+    AlignedFeature::shared("Jitter(%)", "MDVP:Jitter(%)", "Jitter(%)"),
+    AlignedFeature::shared("Jitter(Abs)", "MDVP:Jitter(Abs)", "Jitter(Abs)"),
+    AlignedFeature::shared("Jitter:RAP", "MDVP:RAP", "Jitter:RAP"),
+    AlignedFeature::approximate("Jitter:PPQ", "MDVP:PPQ", "Jitter:PPQ5"),
+    AlignedFeature::shared("Jitter:DDP", "Jitter:DDP", "Jitter:DDP"),
+    AlignedFeature::shared("Shimmer", "MDVP:Shimmer", "Shimmer"),
+    AlignedFeature::shared("Shimmer(dB)", "MDVP:Shimmer(dB)", "Shimmer(dB)"),
+    AlignedFeature::shared("Shimmer:APQ3", "Shimmer:APQ3", "Shimmer:APQ3"),
+    AlignedFeature::shared("Shimmer:APQ5", "Shimmer:APQ5", "Shimmer:APQ5"),
+    AlignedFeature::approximate("Shimmer:APQ11", "MDVP:APQ", "Shimmer:APQ11"),
+    AlignedFeature::shared("Shimmer:DDA", "Shimmer:DDA", "Shimmer:DDA"),
+    AlignedFeature::shared("NHR", "NHR", "NHR"),
+    AlignedFeature::shared("HNR", "HNR", "HNR"),
+    AlignedFeature::shared("RPDE", "RPDE", "RPDE"),
+    AlignedFeature::shared("DFA", "DFA", "DFA"),
+    AlignedFeature::shared("PPE", "PPE", "PPE"),
+    AlignedFeature::classification_only("Fo(Hz)", "MDVP:Fo(Hz)"),
+    AlignedFeature::classification_only("Fhi(Hz)", "MDVP:Fhi(Hz)"),
+    AlignedFeature::classification_only("Flo(Hz)", "MDVP:Flo(Hz)"),
+    AlignedFeature::classification_only("spread1", "spread1"),
+    AlignedFeature::classification_only("spread2", "spread2"),
+    AlignedFeature::classification_only("D2", "D2"),
+];
+
+/// Fichier d'origine d'un échantillon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DatasetSource {
+    Classification,
+    Regression,
+}
+
+impl DatasetSource {
+    /// Noms des features dans l'ordre du chargement
+    pub fn feature_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Classification => &CLASSIFICATION_FEATURES,
+            Self::Regression => &REGRESSION_FEATURES,
+        }
+    }
+}
+
+/// Projette les deux datasets sur un espace de features commun
+///
+/// Les mesures absentes d'un fichier valent 0 et un masque (1 = mesurée, 0 = absente)
+/// peut être concaténé pour qu'un même réseau distingue les deux sources. Les entrées
+/// doivent être brutes (non normalisées par fichier) : `fit` ajuste un seul scaler sur
+/// l'union des deux datasets pour que chaque colonne partagée ait la même échelle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeatureAligner {
+    pub include_mask: bool,
+    pub include_approximate: bool,
+    classification_indices: Vec<Option<usize>>,
+    regression_indices: Vec<Option<usize>>,
+    scaler: Option<MinMaxScaler>,
+}
+
+impl FeatureAligner {
+    /// Crée un aligneur qui concatène le masque de présence ; les mesures approchées
+    /// ne sont partagées qu'après `with_approximate(true)`
+    pub fn new() -> Self {
+        Self {
+            include_mask: true,
+            include_approximate: false,
+            classification_indices: Self::source_indices(DatasetSource::Classification, false),
+            regression_indices: Self::source_indices(DatasetSource::Regression, false),
+            scaler: None,
+        }
+    }
+
+    /// Active ou désactive la concaténation du masque
+    pub fn with_mask(mut self, include_mask: bool) -> Self {
+        self.include_mask = include_mask;
+        self
+    }
+
+    /// Traite les mesures approchées comme communes ; sinon elles ne sont lues que
+    /// dans le fichier de classification
+    pub fn with_approximate(mut self, include_approximate: bool) -> Self {
+        self.include_approximate = include_approximate;
+        self.classification_indices = Self::source_indices(DatasetSource::Classification, include_approximate);
+        self.regression_indices = Self::source_indices(DatasetSource::Regression, include_approximate);
+        self.scaler = None;
+        self
+    }
+
+    /// Ajuste la mise à l'échelle min-max sur l'union des deux datasets bruts
+    ///
+    /// Chaque colonne canonique n'est bornée que par les fichiers qui la mesurent.
+    pub fn fit(&mut self, classification_inputs: &[Array1<f64>], regression_inputs: &[Array1<f64>]) -> &mut Self {
+        let mut rows = self.raw_all(classification_inputs, DatasetSource::Classification);
+        rows.extend(self.raw_all(regression_inputs, DatasetSource::Regression));
+        let mut scaler = MinMaxScaler::new();
+        scaler.fit(&rows);
+        self.scaler = Some(scaler);
+        self
+    }
+
+    /// Noms des mesures canoniques
+    pub fn feature_names() -> Vec<&'static str> {
+        ALIGNED_FEATURES.iter().map(|feature| feature.name).collect()
+    }
+
+    /// Taille des vecteurs produits (22, ou 44 avec le masque)
+    pub fn output_size(&self) -> usize {
+        if self.include_mask { 2 * ALIGNED_FEATURES.len() } else { ALIGNED_FEATURES.len() }
+    }
+
+    /// Index dans le fichier source de chaque mesure canonique
+    pub fn mapping(&self, source: DatasetSource) -> &[Option<usize>] {
+        match source {
+            DatasetSource::Classification => &self.classification_indices,
+            DatasetSource::Regression => &self.regression_indices,
+        }
+    }
+
+    /// Masque de présence des mesures canoniques pour une source
+    pub fn mask(&self, source: DatasetSource) -> Array1<f64> {
+        self.mapping(source).iter()
+            .map(|index| if index.is_some() { 1.0 } else { 0.0 })
+            .collect()
+    }
+
+    /// Aligne un échantillon brut issu de la source indiquée (mis à l'échelle si `fit`
+    /// a été appelé)
+    pub fn align(&self, input: &Array1<f64>, source: DatasetSource) -> Array1<f64> {
+        let mapping = self.mapping(source);
+        let mut aligned = self.raw(input, source);
+        if let Some(scaler) = &self.scaler {
+            aligned = scaler.transform(&aligned);
+        }
+        let mut output: Vec<f64> = mapping.iter().zip(aligned.iter())
+            .map(|(index, &value)| if index.is_some() { value } else { 0.0 })
+            .collect();
+
+        if self.include_mask {
+            output.extend(mapping.iter().map(|index| if index.is_some() { 1.0 } else { 0.0 }));
+        }
+
+        Array1::from_vec(output)
+    }
+
+    /// Aligne tout un ensemble d'échantillons
+    pub fn align_all(&self, inputs: &[Array1<f64>], source: DatasetSource) -> Vec<Array1<f64>> {
+        inputs.iter().map(|input| self.align(input, source)).collect()
+    }
+
+    /// Mesures canoniques non mises à l'échelle ; `NaN` pour celles que la source n'a pas
+    fn raw(&self, input: &Array1<f64>, source: DatasetSource) -> Array1<f64> {
+        self.mapping(source).iter()
+            .map(|index| index.map(|i| input[i]).unwrap_or(f64::NAN))
+            .collect()
+    }
+
+    fn raw_all(&self, inputs: &[Array1<f64>], source: DatasetSource) -> Vec<Array1<f64>> {
+        inputs.iter().map(|input| self.raw(input, source)).collect()
+    }
+
+    fn source_indices(source: DatasetSource, include_approximate: bool) -> Vec<Option<usize>> {
+        let names = source.feature_names();
+        ALIGNED_FEATURES.iter()
+            .map(|feature| {
+                let name = match source {
+                    DatasetSource::Classification => feature.classification,
+                    DatasetSource::Regression if feature.approximate && !include_approximate => None,
+                    DatasetSource::Regression => feature.regression,
+                };
+                name.and_then(|name| names.iter().position(|candidate| *candidate == name))
+            })
+            .collect()
+    }
+}
+
+impl Default for FeatureAligner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,5 +1,44 @@
 use ndarray::Array1;
 use rand::seq::SliceRandom;
+use super::imputation::{ImputationStrategy, Imputer};
+use super::preprocessing::MinMaxScaler;
+
+/// Noms des 22 features de classification, dans l'ordre du chargement
+pub const CLASSIFICATION_FEATURES: [&str; 22] = [
+    "MDVP:Fo(Hz)", "MDVP:Fhi(Hz)", "MDVP:Flo(Hz)",
+    "MDVP:Jitter(%)", "MDVP:Jitter(Abs)", "MDVP:RAP", "MDVP:PPQ", "Jitter:DDP",
+    "MDVP:Shimmer", "MDVP:Shimmer(dB)", "Shimmer:APQ3", "Shimmer:APQ5", "MDVP:APQ", "Shimmer:DDA",
+    "NHR", "HNR", "RPDE", "DFA", "spread1", "spread2", "D2", "PPE",
+];
+
+/// Noms des 16 features de régression, dans l'ordre du chargement
+pub const REGRESSION_FEATURES: [&str; 16] = [
+    "Jitter(%)", "Jitter(Abs)", "Jitter:RAP", "Jitter:PPQ5", "Jitter:DDP",
+    "Shimmer", "Shimmer(dB)", "Shimmer:APQ3", "Shimmer:APQ5", "Shimmer:APQ11", "Shimmer:DDA",
+    "NHR", "HNR", "RPDE", "DFA", "PPE",
+];
+
+/// Seuils de motor UPDRS séparant les classes de sévérité (légère, modérée, marquée)
+pub const UPDRS_SEVERITY_BINS: [f64; 2] = [15.0, 25.0];
+
+/// Classe de sévérité d'un score motor UPDRS: nombre de seuils qu'il atteint
+pub fn severity_class(motor_updrs: f64, bins: &[f64]) -> usize {
+    bins.iter().filter(|&&bin| motor_updrs >= bin).count()
+}
+
+/// Vecteur one-hot de longueur `classes`
+pub fn one_hot(class: usize, classes: usize) -> Array1<f64> {
+    let mut encoded = Array1::zeros(classes);
+    encoded[class] = 1.0;
+    encoded
+}
+
+/// Cibles one-hot de sévérité à partir des cibles de régression (motor UPDRS / 100)
+pub fn severity_targets(regression_targets: &[Array1<f64>], bins: &[f64]) -> Vec<Array1<f64>> {
+    regression_targets.iter()
+        .map(|target| one_hot(severity_class(target[0] * 100.0, bins), bins.len() + 1))
+        .collect()
+}
 
 /// Dataset optimisé pour Parkinson
 #[derive(Debug, Clone)]
@@ -8,6 +47,11 @@ pub struct ParkinsonDataset {
     pub classification_targets: Vec<Array1<f64>>,
     pub regression_inputs: Vec<Array1<f64>>,
     pub regression_targets: Vec<Array1<f64>>,
+    /// Identifiant du sujet de chaque enregistrement (vide si inconnu)
+    pub classification_subjects: Vec<usize>,
+    pub regression_subjects: Vec<usize>,
+    pub classification_scaler: Option<MinMaxScaler>,
+    pub regression_scaler: Option<MinMaxScaler>,
 }
 
 /// Statistiques optimisées
@@ -19,6 +63,12 @@ pub struct DataStats {
     pub regression_features: usize,
 }
 
+impl Default for ParkinsonDataset {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ParkinsonDataset {
     /// Crée un nouveau dataset vide
     pub fn new() -> Self {
@@ -27,17 +77,27 @@ impl ParkinsonDataset {
             classification_targets: Vec::new(),
             regression_inputs: Vec::new(),
             regression_targets: Vec::new(),
+            classification_subjects: Vec::new(),
+            regression_subjects: Vec::new(),
+            classification_scaler: None,
+            regression_scaler: None,
         }
     }
 
     /// Charge tous les données rapidement
+    ///
+    /// Les valeurs manquantes sont imputées par la médiane de tout le fichier ; pour une
+    /// évaluation sans fuite, partir de `load_raw` et ajuster un `Imputer` sur l'entraînement.
     pub fn load_all_data() -> Result<Self, Box<dyn std::error::Error>> {
-        let mut dataset = Self::new();
-        
         println!("📊 Chargement des données Parkinson...");
         
-        dataset.load_classification_data()?;
-        dataset.load_regression_data()?;
+        let mut dataset = Self::load_raw()?;
+        let missing = Imputer::missing_count(&dataset.classification_inputs)
+            + Imputer::missing_count(&dataset.regression_inputs);
+        if missing > 0 {
+            println!("⚠️  {} valeurs manquantes imputées (médiane)", missing);
+        }
+        dataset.impute_missing(ImputationStrategy::Median)?;
         dataset.normalize_features();
         
         let stats = dataset.get_stats();
@@ -47,6 +107,33 @@ impl ParkinsonDataset {
         Ok(dataset)
     }
 
+    /// Charge les données brutes, sans imputation ni normalisation
+    ///
+    /// Les cellules illisibles sont conservées comme `NaN`.
+    pub fn load_raw() -> Result<Self, Box<dyn std::error::Error>> {
+        let mut dataset = Self::new();
+        dataset.load_classification_data()?;
+        dataset.load_regression_data()?;
+        Ok(dataset)
+    }
+
+    /// Impute les valeurs manquantes de chaque dataset avec un imputeur ajusté sur lui-même
+    pub fn impute_missing(&mut self, strategy: ImputationStrategy) -> Result<(), Box<dyn std::error::Error>> {
+        if !self.classification_inputs.is_empty() {
+            let mut imputer = Imputer::new(strategy.clone());
+            imputer.fit(&self.classification_inputs, &self.classification_targets);
+            self.classification_inputs = imputer.transform_all(
+                &self.classification_inputs, Some(&self.classification_targets))?;
+        }
+
+        if !self.regression_inputs.is_empty() {
+            let mut imputer = Imputer::new(strategy);
+            imputer.fit(&self.regression_inputs, &self.regression_targets);
+            self.regression_inputs = imputer.transform_all(&self.regression_inputs, None)?;
+        }
+        Ok(())
+    }
+
     /// Charge les données de classification
     fn load_classification_data(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         let file_path = "parkinsons/parkinsons.data";
@@ -61,25 +148,37 @@ impl ParkinsonDataset {
                 continue;
             }
             
+            // Colonne 0: nom, colonne 17: status, les 22 autres sont les features
             let features: Vec<f64> = record.iter()
+                .enumerate()
                 .skip(1)
+                .filter(|(i, _)| *i != 17)
                 .take(22)
-                .map(|s| s.parse::<f64>().unwrap_or(0.0))
+                .map(|(_, s)| s.parse::<f64>().unwrap_or(f64::NAN))
                 .collect();
             
             if features.len() != 22 {
                 continue;
             }
             
-            let status: f64 = record.get(23).unwrap_or("0").parse().unwrap_or(0.0);
+            let status: f64 = record.get(17).unwrap_or("0").parse().unwrap_or(0.0);
+            let subject = Self::parse_subject_id(record.get(0).unwrap_or(""));
             
             self.classification_inputs.push(Array1::from_vec(features));
+            self.classification_subjects.push(subject);
             self.classification_targets.push(Array1::from_vec(vec![status]));
         }
         
         Ok(())
     }
 
+    /// Extrait le numéro de sujet d'un nom d'enregistrement (`phon_R01_S01_1` → 1)
+    fn parse_subject_id(name: &str) -> usize {
+        name.split('_')
+            .find_map(|part| part.strip_prefix('S').and_then(|id| id.parse().ok()))
+            .unwrap_or(0)
+    }
+
     /// Charge les données de régression
     pub fn load_regression_data(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         let file_path = "parkinsons/parkinsons_updrs.data";
@@ -97,7 +196,7 @@ impl ParkinsonDataset {
             let features: Vec<f64> = record.iter()
                 .skip(6)
                 .take(16)
-                .map(|s| s.parse::<f64>().unwrap_or(0.0))
+                .map(|s| s.parse::<f64>().unwrap_or(f64::NAN))
                 .collect();
             
             if features.len() != 16 {
@@ -106,8 +205,10 @@ impl ParkinsonDataset {
             
             let motor_updrs: f64 = record.get(4).unwrap_or("0").parse().unwrap_or(0.0);
             let normalized_updrs = motor_updrs / 100.0;
+            let subject: usize = record.get(0).unwrap_or("0").parse().unwrap_or(0);
             
             self.regression_inputs.push(Array1::from_vec(features));
+            self.regression_subjects.push(subject);
             self.regression_targets.push(Array1::from_vec(vec![normalized_updrs]));
         }
         
@@ -115,39 +216,28 @@ impl ParkinsonDataset {
     }
 
     /// Normalisation rapide
+    ///
+    /// Les scalers ajustés sont conservés pour normaliser de nouvelles données.
     pub fn normalize_features(&mut self) {
         if !self.classification_inputs.is_empty() {
-            Self::normalize_dataset_fast(&mut self.classification_inputs);
+            self.classification_scaler = Some(Self::normalize_dataset_fast(&mut self.classification_inputs));
         }
         
         if !self.regression_inputs.is_empty() {
-            Self::normalize_dataset_fast(&mut self.regression_inputs);
+            self.regression_scaler = Some(Self::normalize_dataset_fast(&mut self.regression_inputs));
         }
     }
 
-    /// Normalisation optimisée
-    fn normalize_dataset_fast(inputs: &mut Vec<Array1<f64>>) {
-        if inputs.is_empty() { return; }
-        
-        let feature_count = inputs[0].len();
-        let mut mins = vec![f64::INFINITY; feature_count];
-        let mut maxs = vec![f64::NEG_INFINITY; feature_count];
-        
-        for input in inputs.iter() {
-            for (i, &value) in input.iter().enumerate() {
-                if value < mins[i] { mins[i] = value; }
-                if value > maxs[i] { maxs[i] = value; }
-            }
-        }
+    /// Normalisation optimisée (les `NaN` sont ignorés et conservés)
+    fn normalize_dataset_fast(inputs: &mut [Array1<f64>]) -> MinMaxScaler {
+        let mut scaler = MinMaxScaler::new();
+        scaler.fit(inputs);
         
         for input in inputs.iter_mut() {
-            for i in 0..feature_count {
-                let range = maxs[i] - mins[i];
-                if range > 0.0 {
-                    input[i] = (input[i] - mins[i]) / range;
-                }
-            }
+            *input = scaler.transform(input);
         }
+        
+        scaler
     }
 
     /// Statistiques rapides
@@ -168,11 +258,18 @@ impl ParkinsonDataset {
     pub fn shuffle(&mut self) {
         let mut rng = rand::rng();
         
-        Self::shuffle_dataset(&mut self.classification_inputs, &mut self.classification_targets, &mut rng);
-        Self::shuffle_dataset(&mut self.regression_inputs, &mut self.regression_targets, &mut rng);
+        Self::shuffle_dataset(&mut self.classification_inputs, &mut self.classification_targets,
+            &mut self.classification_subjects, &mut rng);
+        Self::shuffle_dataset(&mut self.regression_inputs, &mut self.regression_targets,
+            &mut self.regression_subjects, &mut rng);
     }
 
-    fn shuffle_dataset(inputs: &mut Vec<Array1<f64>>, targets: &mut Vec<Array1<f64>>, rng: &mut rand::rngs::ThreadRng) {
+    fn shuffle_dataset(
+        inputs: &mut Vec<Array1<f64>>,
+        targets: &mut Vec<Array1<f64>>,
+        subjects: &mut Vec<usize>,
+        rng: &mut rand::rngs::ThreadRng,
+    ) {
         let mut indices: Vec<usize> = (0..inputs.len()).collect();
         indices.shuffle(rng);
         
@@ -181,6 +278,10 @@ impl ParkinsonDataset {
         
         *inputs = new_inputs;
         *targets = new_targets;
+        
+        if subjects.len() == indices.len() {
+            *subjects = indices.iter().map(|&i| subjects[i]).collect();
+        }
     }
     
     pub fn analyze_class_distribution(&self) {
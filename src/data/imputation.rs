@@ -0,0 +1,243 @@
+use ndarray::Array1;
+use serde::{Deserialize, Serialize};
+
+/// Stratégies d'imputation des valeurs manquantes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ImputationStrategy {
+    /// Moyenne de la feature sur l'entraînement
+    Mean,
+    /// Médiane de la feature sur l'entraînement
+    Median,
+    /// Médiane calculée séparément pour chaque classe (sain / Parkinson)
+    ClassMedian,
+    /// Moyenne des k plus proches voisins de l'entraînement
+    Knn { k: usize },
+}
+
+/// Imputeur ajusté sur les données d'entraînement
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Imputer {
+    pub strategy: ImputationStrategy,
+    pub add_indicators: bool,
+    feature_count: usize,
+    fill_values: Vec<f64>,
+    class_fill_values: Vec<Vec<f64>>,
+    reference_rows: Vec<Vec<f64>>,
+    indicator_features: Vec<usize>,
+}
+
+impl Imputer {
+    /// Crée un imputeur non ajusté
+    pub fn new(strategy: ImputationStrategy) -> Self {
+        Self {
+            strategy,
+            add_indicators: false,
+            feature_count: 0,
+            fill_values: Vec::new(),
+            class_fill_values: Vec::new(),
+            reference_rows: Vec::new(),
+            indicator_features: Vec::new(),
+        }
+    }
+
+    /// Ajoute une colonne indicatrice (0/1) par feature ayant des valeurs manquantes
+    pub fn with_indicators(mut self, add_indicators: bool) -> Self {
+        self.add_indicators = add_indicators;
+        self
+    }
+
+    /// Indique si l'imputeur a été ajusté
+    pub fn is_fitted(&self) -> bool {
+        self.feature_count > 0
+    }
+
+    /// Nombre de features attendues en entrée
+    pub fn input_size(&self) -> usize {
+        self.feature_count
+    }
+
+    /// Nombre de features en sortie (indicateurs compris)
+    pub fn output_size(&self) -> usize {
+        self.feature_count + if self.add_indicators { self.indicator_features.len() } else { 0 }
+    }
+
+    /// Ajuste l'imputeur sur les données d'entraînement
+    ///
+    /// Les cibles ne sont utilisées que par `ClassMedian`.
+    pub fn fit(&mut self, inputs: &[Array1<f64>], targets: &[Array1<f64>]) -> &mut Self {
+        self.feature_count = inputs.first().map(|input| input.len()).unwrap_or(0);
+
+        let columns = Self::observed_columns(inputs.iter(), self.feature_count);
+        self.fill_values = match self.strategy {
+            ImputationStrategy::Mean => columns.iter().map(|column| mean(column)).collect(),
+            _ => columns.iter().map(|column| median(column)).collect(),
+        };
+
+        self.class_fill_values.clear();
+        if self.strategy == ImputationStrategy::ClassMedian {
+            for class in [false, true] {
+                let rows = inputs.iter().zip(targets.iter())
+                    .filter(|(_, target)| (target[0] > 0.5) == class)
+                    .map(|(input, _)| input);
+                let class_columns = Self::observed_columns(rows, self.feature_count);
+
+                // Repli sur la médiane globale si la classe n'a aucune valeur observée
+                let fills = class_columns.iter().zip(self.fill_values.iter())
+                    .map(|(column, &global)| if column.is_empty() { global } else { median(column) })
+                    .collect();
+                self.class_fill_values.push(fills);
+            }
+        }
+
+        self.reference_rows.clear();
+        if let ImputationStrategy::Knn { .. } = self.strategy {
+            self.reference_rows = inputs.iter().map(|input| input.to_vec()).collect();
+        }
+
+        self.indicator_features = (0..self.feature_count)
+            .filter(|&i| inputs.iter().any(|input| input[i].is_nan()))
+            .collect();
+
+        self
+    }
+
+    /// Impute un échantillon sans connaître sa classe (inférence)
+    ///
+    /// Échoue si l'échantillon n'a pas le nombre de features vu lors de l'ajustement.
+    pub fn transform(&self, input: &Array1<f64>) -> Result<Array1<f64>, Box<dyn std::error::Error>> {
+        self.impute(input, None)
+    }
+
+    /// Impute un échantillon étiqueté (utilise la médiane de sa classe pour `ClassMedian`)
+    pub fn transform_labeled(
+        &self,
+        input: &Array1<f64>,
+        target: &Array1<f64>,
+    ) -> Result<Array1<f64>, Box<dyn std::error::Error>> {
+        self.impute(input, Some(target[0] > 0.5))
+    }
+
+    /// Impute tout un ensemble, avec ou sans étiquettes
+    pub fn transform_all(
+        &self,
+        inputs: &[Array1<f64>],
+        targets: Option<&[Array1<f64>]>,
+    ) -> Result<Vec<Array1<f64>>, Box<dyn std::error::Error>> {
+        match targets {
+            Some(targets) => inputs.iter().zip(targets.iter())
+                .map(|(input, target)| self.transform_labeled(input, target))
+                .collect(),
+            None => inputs.iter().map(|input| self.transform(input)).collect(),
+        }
+    }
+
+    /// Compte les valeurs manquantes d'un ensemble
+    pub fn missing_count(inputs: &[Array1<f64>]) -> usize {
+        inputs.iter()
+            .map(|input| input.iter().filter(|v| v.is_nan()).count())
+            .sum()
+    }
+
+    fn impute(&self, input: &Array1<f64>, positive_class: Option<bool>) -> Result<Array1<f64>, Box<dyn std::error::Error>> {
+        if input.len() != self.feature_count {
+            return Err(format!(
+                "l'échantillon a {} features mais l'imputeur a été ajusté sur {}",
+                input.len(), self.feature_count
+            ).into());
+        }
+
+        let mut values = input.to_vec();
+
+        if values.iter().any(|v| v.is_nan()) {
+            let fills = match (&self.strategy, positive_class) {
+                (ImputationStrategy::ClassMedian, Some(class)) if self.class_fill_values.len() == 2 => {
+                    &self.class_fill_values[class as usize]
+                }
+                _ => &self.fill_values,
+            };
+
+            for i in 0..values.len() {
+                if values[i].is_nan() {
+                    values[i] = match self.strategy {
+                        ImputationStrategy::Knn { k } => self.knn_value(input, i, k)
+                            .unwrap_or(fills[i]),
+                        _ => fills[i],
+                    };
+                }
+            }
+        }
+
+        if self.add_indicators {
+            values.extend(self.indicator_features.iter()
+                .map(|&i| if input[i].is_nan() { 1.0 } else { 0.0 }));
+        }
+
+        Ok(Array1::from_vec(values))
+    }
+
+    /// Moyenne de la feature parmi les k voisins les plus proches (distance euclidienne
+    /// calculée sur les features observées dans les deux lignes)
+    fn knn_value(&self, input: &Array1<f64>, feature: usize, k: usize) -> Option<f64> {
+        let mut neighbours: Vec<(f64, f64)> = self.reference_rows.iter()
+            .filter(|row| !row[feature].is_nan())
+            .filter_map(|row| {
+                let mut sum = 0.0;
+                let mut shared = 0;
+                for (a, b) in input.iter().zip(row.iter()) {
+                    if !a.is_nan() && !b.is_nan() {
+                        sum += (a - b).powi(2);
+                        shared += 1;
+                    }
+                }
+                if shared == 0 {
+                    return None;
+                }
+                // Mise à l'échelle pour comparer des lignes avec peu de features communes
+                let distance = (sum * input.len() as f64 / shared as f64).sqrt();
+                Some((distance, row[feature]))
+            })
+            .collect();
+
+        if neighbours.is_empty() {
+            return None;
+        }
+
+        neighbours.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let k = k.max(1).min(neighbours.len());
+        Some(neighbours[..k].iter().map(|(_, value)| value).sum::<f64>() / k as f64)
+    }
+
+    /// Valeurs observées (non manquantes) de chaque feature
+    fn observed_columns<'a>(rows: impl Iterator<Item = &'a Array1<f64>>, feature_count: usize) -> Vec<Vec<f64>> {
+        let mut columns = vec![Vec::new(); feature_count];
+        for row in rows {
+            for (i, &value) in row.iter().enumerate().take(feature_count) {
+                if !value.is_nan() {
+                    columns[i].push(value);
+                }
+            }
+        }
+        columns
+    }
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+fn median(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
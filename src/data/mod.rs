@@ -1 +1,6 @@
+pub mod alignment;
 pub mod data_loader;
+pub mod imputation;
+pub mod preprocessing;
+pub mod split;
+pub mod synthetic;
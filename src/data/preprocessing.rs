@@ -0,0 +1,249 @@
+use ndarray::Array1;
+use serde::{Deserialize, Serialize};
+use super::imputation::Imputer;
+
+/// Mise à l'échelle min-max dans [0, 1]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MinMaxScaler {
+    pub mins: Vec<f64>,
+    pub maxs: Vec<f64>,
+}
+
+impl MinMaxScaler {
+    /// Crée un scaler non ajusté
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Calcule les bornes de chaque feature (les `NaN` sont ignorés)
+    pub fn fit(&mut self, inputs: &[Array1<f64>]) -> &mut Self {
+        let feature_count = inputs.first().map(|input| input.len()).unwrap_or(0);
+        self.mins = vec![f64::INFINITY; feature_count];
+        self.maxs = vec![f64::NEG_INFINITY; feature_count];
+
+        for input in inputs {
+            for (i, &value) in input.iter().enumerate() {
+                if value < self.mins[i] { self.mins[i] = value; }
+                if value > self.maxs[i] { self.maxs[i] = value; }
+            }
+        }
+        self
+    }
+
+    /// Applique la mise à l'échelle (les features constantes sont laissées telles quelles)
+    pub fn transform(&self, input: &Array1<f64>) -> Array1<f64> {
+        let mut output = input.clone();
+        for i in 0..output.len().min(self.mins.len()) {
+            let range = self.maxs[i] - self.mins[i];
+            if range > 0.0 {
+                output[i] = (output[i] - self.mins[i]) / range;
+            }
+        }
+        output
+    }
+}
+
+/// Standardisation (moyenne nulle, variance unitaire)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StandardScaler {
+    pub means: Vec<f64>,
+    pub stds: Vec<f64>,
+}
+
+impl StandardScaler {
+    /// Crée un scaler non ajusté
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Calcule moyenne et écart-type de chaque feature (les valeurs non finies sont ignorées)
+    pub fn fit(&mut self, inputs: &[Array1<f64>]) -> &mut Self {
+        let feature_count = inputs.first().map(|input| input.len()).unwrap_or(0);
+        self.means = vec![0.0; feature_count];
+        self.stds = vec![0.0; feature_count];
+        let mut counts = vec![0usize; feature_count];
+
+        for input in inputs {
+            for (i, &value) in input.iter().enumerate().filter(|(_, value)| value.is_finite()) {
+                self.means[i] += value;
+                counts[i] += 1;
+            }
+        }
+        for (mean, &count) in self.means.iter_mut().zip(&counts) {
+            *mean /= count.max(1) as f64;
+        }
+        for input in inputs {
+            for (i, &value) in input.iter().enumerate().filter(|(_, value)| value.is_finite()) {
+                self.stds[i] += (value - self.means[i]).powi(2);
+            }
+        }
+        for (std, &count) in self.stds.iter_mut().zip(&counts) {
+            *std = (*std / count.max(1) as f64).sqrt();
+        }
+        self
+    }
+
+    /// Applique la standardisation (les features constantes sont seulement centrées)
+    pub fn transform(&self, input: &Array1<f64>) -> Array1<f64> {
+        let mut output = input.clone();
+        for i in 0..output.len().min(self.means.len()) {
+            output[i] -= self.means[i];
+            if self.stds[i] > 0.0 {
+                output[i] /= self.stds[i];
+            }
+        }
+        output
+    }
+}
+
+/// Transformation log(1 + x) des features très asymétriques (jitter, shimmer, NHR...)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LogTransform {
+    pub features: Vec<usize>,
+    pub input_size: usize,
+}
+
+impl LogTransform {
+    /// Transformation appliquée aux features indiquées
+    pub fn new(features: Vec<usize>) -> Self {
+        Self { features, input_size: 0 }
+    }
+
+    /// Enregistre la largeur des données et vérifie que les features indiquées existent
+    pub fn fit(&mut self, inputs: &[Array1<f64>]) -> Result<&mut Self, Box<dyn std::error::Error>> {
+        self.input_size = inputs.first().map(|input| input.len()).unwrap_or(0);
+        if let Some(&index) = self.features.iter().find(|&&i| i >= self.input_size) {
+            return Err(format!(
+                "feature {} transformée mais les données n'en ont que {}",
+                index, self.input_size
+            ).into());
+        }
+        Ok(self)
+    }
+
+    /// Applique log(1 + x) ; les valeurs inférieures ou égales à -1, hors domaine, sont ramenées à 0
+    pub fn transform(&self, input: &Array1<f64>) -> Array1<f64> {
+        let mut output = input.clone();
+        for &i in &self.features {
+            if i < output.len() {
+                output[i] = if output[i] <= -1.0 { 0.0 } else { output[i].ln_1p() };
+            }
+        }
+        output
+    }
+}
+
+/// Sélection de features, fixe ou par seuil de variance
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FeatureSelector {
+    pub variance_threshold: Option<f64>,
+    pub selected: Vec<usize>,
+    pub input_size: usize,
+}
+
+impl FeatureSelector {
+    /// Conserve les features indiquées
+    pub fn indices(selected: Vec<usize>) -> Self {
+        Self { variance_threshold: None, selected, input_size: 0 }
+    }
+
+    /// Conserve les features dont la variance d'entraînement dépasse le seuil
+    pub fn variance_threshold(threshold: f64) -> Self {
+        Self { variance_threshold: Some(threshold), selected: Vec::new(), input_size: 0 }
+    }
+
+    /// Détermine les features retenues ; pour une sélection fixe, vérifie seulement que
+    /// les indices existent dans les données
+    pub fn fit(&mut self, inputs: &[Array1<f64>]) -> Result<&mut Self, Box<dyn std::error::Error>> {
+        self.input_size = inputs.first().map(|input| input.len()).unwrap_or(0);
+        match self.variance_threshold {
+            Some(threshold) => {
+                let mut scaler = StandardScaler::new();
+                scaler.fit(inputs);
+                self.selected = scaler.stds.iter()
+                    .enumerate()
+                    .filter(|(_, std)| std.powi(2) > threshold)
+                    .map(|(i, _)| i)
+                    .collect();
+            }
+            None => {
+                if let Some(&index) = self.selected.iter().find(|&&i| i >= self.input_size) {
+                    return Err(format!(
+                        "feature {} sélectionnée mais les données n'en ont que {}",
+                        index, self.input_size
+                    ).into());
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// Extrait les features retenues
+    pub fn transform(&self, input: &Array1<f64>) -> Array1<f64> {
+        self.selected.iter().map(|&i| input[i]).collect()
+    }
+}
+
+/// Étape de prétraitement ajustable et sérialisable
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Transformer {
+    Impute(Imputer),
+    MinMax(MinMaxScaler),
+    Standard(StandardScaler),
+    Log(LogTransform),
+    Select(FeatureSelector),
+}
+
+impl Transformer {
+    /// Ajuste l'étape sur les données d'entraînement
+    pub fn fit(&mut self, inputs: &[Array1<f64>], targets: &[Array1<f64>]) -> Result<(), Box<dyn std::error::Error>> {
+        match self {
+            Self::Impute(imputer) => { imputer.fit(inputs, targets); }
+            Self::MinMax(scaler) => { scaler.fit(inputs); }
+            Self::Standard(scaler) => { scaler.fit(inputs); }
+            Self::Log(log) => { log.fit(inputs)?; }
+            Self::Select(selector) => { selector.fit(inputs)?; }
+        }
+        Ok(())
+    }
+
+    /// Nombre de features attendues en entrée, enregistré à l'ajustement
+    pub fn input_size(&self) -> usize {
+        match self {
+            Self::Impute(imputer) => imputer.input_size(),
+            Self::MinMax(scaler) => scaler.mins.len(),
+            Self::Standard(scaler) => scaler.means.len(),
+            Self::Log(log) => log.input_size,
+            Self::Select(selector) => selector.input_size,
+        }
+    }
+
+    /// Transforme un échantillon (inférence) ; la largeur doit être celle vue à l'ajustement
+    pub fn transform(&self, input: &Array1<f64>) -> Result<Array1<f64>, Box<dyn std::error::Error>> {
+        if input.len() != self.input_size() {
+            return Err(format!(
+                "l'échantillon a {} features mais l'étape a été ajustée sur {}",
+                input.len(), self.input_size()
+            ).into());
+        }
+        Ok(match self {
+            Self::Impute(imputer) => imputer.transform(input)?,
+            Self::MinMax(scaler) => scaler.transform(input),
+            Self::Standard(scaler) => scaler.transform(input),
+            Self::Log(log) => log.transform(input),
+            Self::Select(selector) => selector.transform(input),
+        })
+    }
+
+    /// Transforme un ensemble ; les étiquettes servent à l'imputation par classe
+    pub fn transform_all(
+        &self,
+        inputs: &[Array1<f64>],
+        targets: Option<&[Array1<f64>]>,
+    ) -> Result<Vec<Array1<f64>>, Box<dyn std::error::Error>> {
+        match self {
+            Self::Impute(imputer) => imputer.transform_all(inputs, targets),
+            _ => inputs.iter().map(|input| self.transform(input)).collect(),
+        }
+    }
+}
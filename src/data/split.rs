@@ -0,0 +1,247 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+use ndarray::Array1;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+
+use super::alignment::DatasetSource;
+use super::data_loader::ParkinsonDataset;
+
+/// Version du format de manifeste
+pub const MANIFEST_VERSION: u32 = 1;
+
+/// Entrées et cibles d'une partie du découpage
+pub type SplitData = (Vec<Array1<f64>>, Vec<Array1<f64>>);
+
+/// Enregistrement affecté à une partie du découpage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SplitEntry {
+    pub row: usize,
+    pub subject: usize,
+}
+
+/// Découpage train/validation/test figé, rejouable par toute l'équipe
+///
+/// Le jeu de test est verrouillé par défaut : il ne peut être extrait qu'après un appel
+/// explicite à `unlock_test`, et `verify` refuse tout manifeste où une ligne est affectée
+/// deux fois ou un sujet apparaît dans plusieurs parties.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SplitManifest {
+    pub version: u32,
+    pub source: DatasetSource,
+    /// Empreinte FNV-1a 64 bits des données découpées
+    pub content_hash: String,
+    pub seed: u64,
+    pub test_locked: bool,
+    pub train: Vec<SplitEntry>,
+    pub validation: Vec<SplitEntry>,
+    pub test: Vec<SplitEntry>,
+}
+
+impl SplitManifest {
+    /// Découpe par sujet : tous les enregistrements d'un sujet vont dans la même partie
+    pub fn by_subject(
+        dataset: &ParkinsonDataset,
+        source: DatasetSource,
+        validation_ratio: f64,
+        test_ratio: f64,
+        seed: u64,
+    ) -> Self {
+        let (inputs, _, _) = source_data(dataset, source);
+        let subjects = row_subjects(dataset, source);
+
+        let mut unique: Vec<usize> = subjects.iter().cloned().collect::<BTreeSet<_>>().into_iter().collect();
+        let mut rng = StdRng::seed_from_u64(seed);
+        unique.shuffle(&mut rng);
+
+        let total = inputs.len() as f64;
+        let mut test_subjects = BTreeSet::new();
+        let mut validation_subjects = BTreeSet::new();
+        let mut test_rows = 0;
+        let mut validation_rows = 0;
+
+        for subject in unique {
+            let rows = subjects.iter().filter(|&&s| s == subject).count();
+            if (test_rows as f64) < total * test_ratio {
+                test_subjects.insert(subject);
+                test_rows += rows;
+            } else if (validation_rows as f64) < total * validation_ratio {
+                validation_subjects.insert(subject);
+                validation_rows += rows;
+            }
+        }
+
+        let mut manifest = Self {
+            version: MANIFEST_VERSION,
+            source,
+            content_hash: content_hash(dataset, source),
+            seed,
+            test_locked: true,
+            train: Vec::new(),
+            validation: Vec::new(),
+            test: Vec::new(),
+        };
+
+        for (row, &subject) in subjects.iter().enumerate() {
+            let entry = SplitEntry { row, subject };
+            if test_subjects.contains(&subject) {
+                manifest.test.push(entry);
+            } else if validation_subjects.contains(&subject) {
+                manifest.validation.push(entry);
+            } else {
+                manifest.train.push(entry);
+            }
+        }
+
+        manifest
+    }
+
+    /// Vérifie que le manifeste correspond au dataset et qu'aucune fuite n'est possible
+    pub fn verify(&self, dataset: &ParkinsonDataset) -> Result<(), Box<dyn std::error::Error>> {
+        if self.version > MANIFEST_VERSION {
+            return Err(format!("version de manifeste {} non supportée", self.version).into());
+        }
+
+        let hash = content_hash(dataset, self.source);
+        if hash != self.content_hash {
+            return Err(format!(
+                "le dataset ne correspond pas au manifeste (empreinte {} au lieu de {})",
+                hash, self.content_hash
+            ).into());
+        }
+
+        let subjects = row_subjects(dataset, self.source);
+        for entry in self.train.iter().chain(&self.validation).chain(&self.test) {
+            if subjects.get(entry.row) != Some(&entry.subject) {
+                return Err(format!("ligne {} absente ou affectée à un autre sujet", entry.row).into());
+            }
+        }
+
+        let mut rows = BTreeSet::new();
+        let mut subject_parts = BTreeMap::new();
+        let parts = [("entraînement", &self.train), ("validation", &self.validation), ("test", &self.test)];
+        for (part, entries) in parts {
+            for entry in entries {
+                if !rows.insert(entry.row) {
+                    return Err(format!("ligne {} affectée plusieurs fois", entry.row).into());
+                }
+                let first_part = *subject_parts.entry(entry.subject).or_insert(part);
+                if first_part != part {
+                    return Err(format!(
+                        "fuite entre parties: sujet {} présent en {} et en {}",
+                        entry.subject, first_part, part
+                    ).into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Déverrouille le jeu de test pour l'évaluation finale
+    pub fn unlock_test(&mut self) {
+        self.test_locked = false;
+    }
+
+    /// Données d'entraînement
+    pub fn train_set(&self, dataset: &ParkinsonDataset) -> Result<SplitData, Box<dyn std::error::Error>> {
+        self.verify(dataset)?;
+        Ok(self.extract(dataset, &self.train))
+    }
+
+    /// Données de validation
+    pub fn validation_set(&self, dataset: &ParkinsonDataset) -> Result<SplitData, Box<dyn std::error::Error>> {
+        self.verify(dataset)?;
+        Ok(self.extract(dataset, &self.validation))
+    }
+
+    /// Données de test (erreur tant que le test est verrouillé)
+    pub fn test_set(&self, dataset: &ParkinsonDataset) -> Result<SplitData, Box<dyn std::error::Error>> {
+        if self.test_locked {
+            return Err("le jeu de test est verrouillé (appeler unlock_test pour l'évaluation finale)".into());
+        }
+        self.verify(dataset)?;
+        Ok(self.extract(dataset, &self.test))
+    }
+
+    /// Sauvegarde le manifeste en JSON
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer_pretty(writer, self)?;
+        Ok(())
+    }
+
+    /// Recharge un manifeste ; le test est toujours verrouillé au chargement
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut manifest: Self = serde_json::from_reader(reader)?;
+        if manifest.version > MANIFEST_VERSION {
+            return Err(format!("version de manifeste {} non supportée", manifest.version).into());
+        }
+        manifest.test_locked = true;
+        Ok(manifest)
+    }
+
+    fn extract(&self, dataset: &ParkinsonDataset, entries: &[SplitEntry]) -> SplitData {
+        let (inputs, targets, _) = source_data(dataset, self.source);
+        entries.iter()
+            .map(|entry| (inputs[entry.row].clone(), targets[entry.row].clone()))
+            .unzip()
+    }
+}
+
+/// Empreinte FNV-1a 64 bits des features, cibles et sujets d'une source
+pub fn content_hash(dataset: &ParkinsonDataset, source: DatasetSource) -> String {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let (inputs, targets, subjects) = source_data(dataset, source);
+    let mut hash = OFFSET;
+    let mut feed = |bytes: [u8; 8]| {
+        for byte in bytes {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(PRIME);
+        }
+    };
+
+    for (input, target) in inputs.iter().zip(targets.iter()) {
+        for value in input.iter().chain(target.iter()) {
+            feed(value.to_bits().to_le_bytes());
+        }
+    }
+    for &subject in subjects {
+        feed((subject as u64).to_le_bytes());
+    }
+
+    format!("{:016x}", hash)
+}
+
+fn source_data(dataset: &ParkinsonDataset, source: DatasetSource) -> (&[Array1<f64>], &[Array1<f64>], &[usize]) {
+    match source {
+        DatasetSource::Classification => (
+            &dataset.classification_inputs,
+            &dataset.classification_targets,
+            &dataset.classification_subjects,
+        ),
+        DatasetSource::Regression => (
+            &dataset.regression_inputs,
+            &dataset.regression_targets,
+            &dataset.regression_subjects,
+        ),
+    }
+}
+
+/// Sujet de chaque ligne ; sans identifiants, chaque ligne est son propre sujet
+fn row_subjects(dataset: &ParkinsonDataset, source: DatasetSource) -> Vec<usize> {
+    let (inputs, _, subjects) = source_data(dataset, source);
+    if subjects.len() == inputs.len() {
+        subjects.to_vec()
+    } else {
+        (0..inputs.len()).collect()
+    }
+}
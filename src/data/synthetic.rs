@@ -0,0 +1,229 @@
+use ndarray::{Array1, Array2};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rand_distr::StandardNormal;
+use super::data_loader::ParkinsonDataset;
+
+/// Part de la diagonale mélangée à la covariance empirique (rend la matrice inversible :
+/// plusieurs mesures sont des combinaisons exactes d'autres, ex. DDP = 3 × RAP)
+const COVARIANCE_SHRINKAGE: f64 = 0.05;
+
+/// Paramètres de génération
+#[derive(Debug, Clone)]
+pub struct SyntheticConfig {
+    pub classification_samples: usize,
+    pub regression_samples: usize,
+    /// Proportion d'enregistrements Parkinson en classification
+    pub positive_ratio: f64,
+    pub classification_subjects: usize,
+    pub regression_subjects: usize,
+    /// Part de la variance expliquée par le sujet (0 = enregistrements indépendants)
+    pub subject_variance: f64,
+    pub seed: u64,
+}
+
+impl Default for SyntheticConfig {
+    /// Dimensions proches des fichiers d'origine
+    fn default() -> Self {
+        Self {
+            classification_samples: 195,
+            regression_samples: 5875,
+            positive_ratio: 0.75,
+            classification_subjects: 32,
+            regression_subjects: 42,
+            subject_variance: 0.5,
+            seed: 42,
+        }
+    }
+}
+
+/// Loi normale multivariée (moyenne + facteur de Cholesky de la covariance)
+#[derive(Debug, Clone)]
+pub struct GaussianModel {
+    pub mean: Array1<f64>,
+    pub cholesky: Array2<f64>,
+}
+
+impl GaussianModel {
+    /// Ajuste la loi sur des lignes complètes (les lignes contenant `NaN` sont ignorées)
+    pub fn fit(rows: &[Array1<f64>]) -> Option<Self> {
+        let rows: Vec<&Array1<f64>> = rows.iter()
+            .filter(|row| row.iter().all(|v| v.is_finite()))
+            .collect();
+        let dim = rows.first()?.len();
+        let n = rows.len() as f64;
+
+        let mut mean = Array1::zeros(dim);
+        for row in &rows {
+            mean += *row;
+        }
+        mean /= n;
+
+        let mut covariance = Array2::zeros((dim, dim));
+        for row in &rows {
+            let centered = *row - &mean;
+            let column = centered.view().insert_axis(ndarray::Axis(1));
+            let line = centered.view().insert_axis(ndarray::Axis(0));
+            covariance += &column.dot(&line);
+        }
+        covariance /= (n - 1.0).max(1.0);
+
+        for i in 0..dim {
+            for j in 0..dim {
+                if i != j {
+                    covariance[[i, j]] *= 1.0 - COVARIANCE_SHRINKAGE;
+                }
+            }
+            covariance[[i, i]] += 1e-12;
+        }
+
+        Some(Self { mean, cholesky: cholesky(&covariance)? })
+    }
+
+    /// Tire un vecteur centré de covariance `scale` × Σ
+    fn sample_centered(&self, rng: &mut StdRng, scale: f64) -> Array1<f64> {
+        let z: Array1<f64> = (0..self.mean.len()).map(|_| rng.sample(StandardNormal)).collect();
+        self.cholesky.dot(&z) * scale.sqrt()
+    }
+}
+
+/// Générateur de données vocales synthétiques ajusté sur un `ParkinsonDataset`
+///
+/// La classification utilise une gaussienne par classe ; la régression une gaussienne
+/// jointe sur (features, UPDRS) pour conserver la corrélation avec la cible.
+#[derive(Debug, Clone)]
+pub struct SyntheticGenerator {
+    pub healthy: Option<GaussianModel>,
+    pub parkinson: Option<GaussianModel>,
+    pub regression: Option<GaussianModel>,
+}
+
+impl SyntheticGenerator {
+    /// Ajuste les lois sur les données disponibles
+    pub fn fit(dataset: &ParkinsonDataset) -> Self {
+        let class_rows = |positive: bool| -> Vec<Array1<f64>> {
+            dataset.classification_inputs.iter()
+                .zip(dataset.classification_targets.iter())
+                .filter(|(_, target)| (target[0] > 0.5) == positive)
+                .map(|(input, _)| input.clone())
+                .collect()
+        };
+
+        let joint_rows: Vec<Array1<f64>> = dataset.regression_inputs.iter()
+            .zip(dataset.regression_targets.iter())
+            .map(|(input, target)| input.iter().chain(target.iter()).cloned().collect())
+            .collect();
+
+        Self {
+            healthy: GaussianModel::fit(&class_rows(false)),
+            parkinson: GaussianModel::fit(&class_rows(true)),
+            regression: GaussianModel::fit(&joint_rows),
+        }
+    }
+
+    /// Génère un nouveau dataset selon la configuration
+    ///
+    /// Échoue si la configuration demande des enregistrements d'un groupe (classe ou
+    /// régression) dont la loi n'a pas pu être ajustée.
+    pub fn sample(&self, config: &SyntheticConfig) -> Result<ParkinsonDataset, Box<dyn std::error::Error>> {
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let mut dataset = ParkinsonDataset::new();
+        let subject_variance = config.subject_variance.clamp(0.0, 0.99);
+
+        // Classification : les sujets sont répartis entre classes selon le ratio demandé
+        let positives = (config.classification_samples as f64 * config.positive_ratio.clamp(0.0, 1.0)).round() as usize;
+        let negatives = config.classification_samples - positives;
+        let subjects = config.classification_subjects.max(1);
+        let positive_subjects = if positives == 0 {
+            0
+        } else if negatives == 0 {
+            subjects
+        } else {
+            ((subjects as f64 * config.positive_ratio).round() as usize).clamp(1, subjects.saturating_sub(1).max(1))
+        };
+
+        let groups = [
+            ("Parkinson", self.parkinson.as_ref(), positives, 1, positive_subjects.max(1), 1.0),
+            ("sain", self.healthy.as_ref(), negatives, positive_subjects + 1, (subjects - positive_subjects).max(1), 0.0),
+        ];
+        for (name, model, count, first_subject, subject_count, label) in groups {
+            if count == 0 {
+                continue;
+            }
+            let model = model.ok_or_else(|| format!(
+                "{} enregistrements de la classe {} demandés mais sa loi n'a pas pu être ajustée",
+                count, name
+            ))?;
+            let rows = Self::sample_subjects(model, count, subject_count, subject_variance, &mut rng);
+            for (subject, row) in rows {
+                dataset.classification_inputs.push(row);
+                dataset.classification_targets.push(Array1::from_vec(vec![label]));
+                dataset.classification_subjects.push(first_subject + subject);
+            }
+        }
+
+        // Régression : la dernière dimension de la loi jointe est l'UPDRS normalisé
+        if config.regression_samples > 0 {
+            let model = self.regression.as_ref().ok_or_else(|| format!(
+                "{} enregistrements de régression demandés mais la loi jointe n'a pas pu être ajustée",
+                config.regression_samples
+            ))?;
+            let rows = Self::sample_subjects(
+                model, config.regression_samples, config.regression_subjects.max(1), subject_variance, &mut rng);
+            let feature_count = model.mean.len() - 1;
+            for (subject, row) in rows {
+                dataset.regression_inputs.push(row.slice(ndarray::s![..feature_count]).to_owned());
+                dataset.regression_targets.push(Array1::from_vec(vec![row[feature_count].max(0.0)]));
+                dataset.regression_subjects.push(1 + subject);
+            }
+        }
+
+        Ok(dataset)
+    }
+
+    /// Tire `count` enregistrements répartis sur `subjects` sujets
+    ///
+    /// Chaque sujet a un centre propre ; ses enregistrements varient autour de ce centre.
+    fn sample_subjects(
+        model: &GaussianModel,
+        count: usize,
+        subjects: usize,
+        subject_variance: f64,
+        rng: &mut StdRng,
+    ) -> Vec<(usize, Array1<f64>)> {
+        let centers: Vec<Array1<f64>> = (0..subjects)
+            .map(|_| &model.mean + &model.sample_centered(rng, subject_variance))
+            .collect();
+
+        (0..count)
+            .map(|i| {
+                let subject = i % subjects;
+                let row = &centers[subject] + &model.sample_centered(rng, 1.0 - subject_variance);
+                (subject, row)
+            })
+            .collect()
+    }
+}
+
+/// Décomposition de Cholesky (None si la matrice n'est pas définie positive)
+fn cholesky(matrix: &Array2<f64>) -> Option<Array2<f64>> {
+    let n = matrix.nrows();
+    let mut lower = Array2::zeros((n, n));
+
+    for i in 0..n {
+        for j in 0..=i {
+            let sum: f64 = (0..j).map(|k| lower[[i, k]] * lower[[j, k]]).sum();
+            if i == j {
+                let value = matrix[[i, i]] - sum;
+                if value <= 0.0 {
+                    return None;
+                }
+                lower[[i, j]] = value.sqrt();
+            } else {
+                lower[[i, j]] = (matrix[[i, j]] - sum) / lower[[j, j]];
+            }
+        }
+    }
+
+    Some(lower)
+}
@@ -0,0 +1,3 @@
+pub mod data;
+pub mod neural_network;
+pub mod pipeline;
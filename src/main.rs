@@ -1,15 +1,20 @@
-mod neural_network;
-mod data;
-
-use neural_network::{NeuralNetwork, Activation, TrainingMetrics};
-use data::data_loader::ParkinsonDataset;
+use ndarray::Array1;
+use model_compilator::neural_network::{
+    NeuralNetwork, Activation, BinaryCrossEntropy, EarlyStopping, Loss, LossKind, LrFinderConfig, NormalizationKind, SamConfig,
+    Trainer, TrainingConfig, TrainingMetrics, ValidationMetric,
+};
+use model_compilator::data::alignment::DatasetSource;
+use model_compilator::data::data_loader::{
+    severity_class, severity_targets, ParkinsonDataset, CLASSIFICATION_FEATURES, REGRESSION_FEATURES, UPDRS_SEVERITY_BINS,
+};
+use model_compilator::data::split::SplitManifest;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("🚀 LANCEMENT AVEC RÉÉQUILIBRAGE MANUEL");
     
     // 1. CHARGEMENT ET ANALYSE
     println!("\n📥 Chargement et analyse des datasets...");
-    let mut dataset = ParkinsonDataset::load_all_data()?;
+    let dataset = ParkinsonDataset::load_all_data()?;
     dataset.analyze_class_distribution(); // Nouvelle méthode
     
     // 2. RÉÉQUILIBRAGE MANUEL SI NÉCESSAIRE
@@ -24,24 +29,45 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("\n🧠 CRÉATION RÉSEAUX POUR DATASET ÉQUILIBRÉ:");
     
     // CLASSIFICATION - Architecture simplifiée pour petit dataset
-    let mut classification_network = NeuralNetwork::new(0.01);
+    // (taux d'apprentissage fixé plus bas par le test de plage)
+    let mut classification_network = NeuralNetwork::new(0.0);
     classification_network
         .add_layer(22, 32, Activation::Relu)
         .add_layer(32, 16, Activation::Relu)
         .add_layer(16, 1, Activation::Sigmoid);
+    classification_network.set_loss(LossKind::bce());
     
-    // RÉGRESSION - Même architecture
-    let mut regression_network = NeuralNetwork::new(0.008);
+    // RÉGRESSION - Réseau profond, stabilisé par BatchNorm sur les couches cachées
+    let mut regression_network = NeuralNetwork::new(0.0);
     regression_network
         .add_layer(16, 128, Activation::Relu)
+        .add_normalization(NormalizationKind::batch())
         .add_layer(128, 64, Activation::Relu)
+        .add_normalization(NormalizationKind::batch())
         .add_layer(64, 32, Activation::Relu)
+        .add_normalization(NormalizationKind::batch())
         .add_layer(32, 1, Activation::Linear);
     
     println!("✅ Classification: 22→32→16→1 (3 couches simplifiées)");
-    println!("✅ Régression: 16→128→64→32→1 (4 couches)");
+    println!("✅ Régression: 16→128→64→32→1 (4 couches, BatchNorm)");
+    
+    // 4. CHOIX DES LEARNING RATES PAR TEST DE PLAGE
+    println!("\n🔎 TEST DE PLAGE DES LEARNING RATES...");
+    let class_lr = classification_network.find_learning_rate(
+        &balanced_dataset.classification_inputs,
+        &balanced_dataset.classification_targets,
+        &LrFinderConfig { batch_size: 8, ..LrFinderConfig::default() },
+    )?.suggested_lr;
+    classification_network.set_learning_rate(class_lr);
+    
+    let reg_lr = regression_network.find_learning_rate(
+        &dataset.regression_inputs,
+        &dataset.regression_targets,
+        &LrFinderConfig { batch_size: 64, ..LrFinderConfig::default() },
+    )?.suggested_lr;
+    regression_network.set_learning_rate(reg_lr);
     
-    // 4. ENTRAÎNEMENT AVEC TECHNIQUES SPÉCIALES
+    // 5. ENTRAÎNEMENT AVEC TECHNIQUES SPÉCIALES
     println!("\n🎯 ENTRAÎNEMENT AVEC RÉÉQUILIBRAGE...");
     let class_metrics = classification_network.train_with_class_weights(
         &balanced_dataset.classification_inputs,
@@ -58,7 +84,31 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         64
     );
     
-    // [Reste du code identique...]
+    // 6. ÉVALUATION ET RAPPORT
+    println!("\n📊 ÉVALUATION FINALE...");
+    let (_, accuracy, precision, recall, f1_score) =
+        evaluate_classification_corrected(&classification_network, &dataset);
+    test_classification_complete_corrected(&classification_network, &dataset);
+    test_regression_complete(&regression_network, &dataset);
+    generate_corrected_report(&class_metrics, &reg_metrics, accuracy, precision, recall, f1_score);
+    compare_sam_on_validation(&dataset)?;
+    classify_updrs_severity(&dataset)?;
+
+    // 7. SAUVEGARDE DES MODÈLES
+    std::fs::create_dir_all("models")?;
+    classification_network.set_feature_names(&CLASSIFICATION_FEATURES);
+    if let Some(scaler) = &dataset.classification_scaler {
+        classification_network.set_scaler(scaler.clone());
+    }
+    classification_network.save("models/classification.json")?;
+
+    regression_network.set_feature_names(&REGRESSION_FEATURES);
+    if let Some(scaler) = &dataset.regression_scaler {
+        regression_network.set_scaler(scaler.clone());
+    }
+    regression_network.save("models/regression.json")?;
+    println!("\n💾 Modèles sauvegardés dans models/");
+
     Ok(())
 }
 
@@ -69,16 +119,20 @@ fn manually_balance_dataset(original: &ParkinsonDataset) -> ParkinsonDataset {
     // Séparer les classes
     let mut parkinson_samples = Vec::new();
     let mut parkinson_targets = Vec::new();
+    let mut parkinson_subjects = Vec::new();
     let mut sain_samples = Vec::new();
     let mut sain_targets = Vec::new();
+    let mut sain_subjects = Vec::new();
     
     for i in 0..original.classification_inputs.len() {
         if original.classification_targets[i][0] > 0.5 {
             parkinson_samples.push(original.classification_inputs[i].clone());
             parkinson_targets.push(original.classification_targets[i].clone());
+            parkinson_subjects.push(original.classification_subjects[i]);
         } else {
             sain_samples.push(original.classification_inputs[i].clone());
             sain_targets.push(original.classification_targets[i].clone());
+            sain_subjects.push(original.classification_subjects[i]);
         }
     }
     
@@ -96,6 +150,7 @@ fn manually_balance_dataset(original: &ParkinsonDataset) -> ParkinsonDataset {
             for i in 0..parkinson_samples.len() {
                 balanced.classification_inputs.push(parkinson_samples[i].clone());
                 balanced.classification_targets.push(parkinson_targets[i].clone());
+                balanced.classification_subjects.push(parkinson_subjects[i]);
             }
         }
         
@@ -103,16 +158,19 @@ fn manually_balance_dataset(original: &ParkinsonDataset) -> ParkinsonDataset {
         for i in 0..sain_samples.len() {
             balanced.classification_inputs.push(sain_samples[i].clone());
             balanced.classification_targets.push(sain_targets[i].clone());
+            balanced.classification_subjects.push(sain_subjects[i]);
         }
     } else {
         // Dataset déjà raisonnablement équilibré
         balanced.classification_inputs = original.classification_inputs.clone();
         balanced.classification_targets = original.classification_targets.clone();
+        balanced.classification_subjects = original.classification_subjects.clone();
     }
     
     // Copier les données de régression
     balanced.regression_inputs = original.regression_inputs.clone();
     balanced.regression_targets = original.regression_targets.clone();
+    balanced.regression_subjects = original.regression_subjects.clone();
     
     println!("📊 Après rééquilibrage: {} samples classification", 
         balanced.classification_inputs.len());
@@ -131,13 +189,15 @@ fn evaluate_classification_corrected(
     let mut false_negatives = 0;
     let mut total_loss = 0.0;
     
+    let cross_entropy = BinaryCrossEntropy::new();
+    
     for i in 0..dataset.classification_inputs.len() {
-        let prediction = network.forward(&dataset.classification_inputs[i]);
+        let (prediction, cache) = network.forward_with_cache(&dataset.classification_inputs[i]);
         let target = &dataset.classification_targets[i][0];
         
-        // Calcul loss binaire cross-entropy approximative
-        let loss = - (target * prediction[0].ln() + (1.0 - target) * (1.0 - prediction[0]).ln());
-        total_loss += loss;
+        // Entropie croisée calculée sur les logits (jamais de ln(0))
+        let logits = &cache[cache.len() - 1].z;
+        total_loss += cross_entropy.value(logits, &prediction, &dataset.classification_targets[i]);
         
         let predicted_class = prediction[0] > 0.5;
         let actual_class = *target > 0.5;
@@ -241,6 +301,107 @@ fn test_regression_complete(network: &NeuralNetwork, dataset: &ParkinsonDataset)
     println!("   📊 Erreur moyenne: {:.1} points UPDRS (sur {} samples)", avg_error, test_samples);
 }
 
+/// Compare SGD seul et SAM/ASAM sur une validation par sujet (architecture identique)
+fn compare_sam_on_validation(dataset: &ParkinsonDataset) -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n🏔️  EFFET DE SAM SUR LA VALIDATION (découpage par sujet):");
+
+    let manifest = SplitManifest::by_subject(dataset, DatasetSource::Classification, 0.25, 0.0, 7);
+    let (train_inputs, train_targets) = manifest.train_set(dataset)?;
+    let (val_inputs, val_targets) = manifest.validation_set(dataset)?;
+
+    let build = |learning_rate: f64| {
+        let mut network = NeuralNetwork::new(learning_rate);
+        network
+            .add_layer(22, 32, Activation::Relu)
+            .add_layer(32, 16, Activation::Relu)
+            .add_layer(16, 1, Activation::Sigmoid);
+        network
+    };
+
+    // Même taux pour les trois modes, choisi sur l'entraînement seul
+    let learning_rate = build(0.0).find_learning_rate(
+        &train_inputs,
+        &train_targets,
+        &LrFinderConfig { batch_size: 8, ..LrFinderConfig::default() },
+    )?.suggested_lr;
+
+    // Même planification, patience et validation: seule la stratégie par batch change
+    let config = TrainingConfig { epochs: 150, batch_size: 8, ..TrainingConfig::default() };
+    let validation = Some((val_inputs.as_slice(), val_targets.as_slice()));
+    let train = |sam: Option<SamConfig>| {
+        let mut network = build(learning_rate);
+        Trainer::new(TrainingConfig { sam, ..config.clone() })
+            .fit(&mut network, &train_inputs, &train_targets, validation);
+        network
+    };
+    let baseline = train(None);
+    let sam = train(Some(SamConfig::sam()));
+    let asam = train(Some(SamConfig::asam()));
+
+    println!("   Mode      Loss train   MSE val   Accuracy val");
+    for (name, network) in [("SGD", &baseline), ("SAM", &sam), ("ASAM", &asam)] {
+        println!("   {:<8}  {:.6}     {:.6}  {:.1}%",
+            name,
+            network.evaluate_complete(&train_inputs, &train_targets),
+            network.evaluate_complete(&val_inputs, &val_targets),
+            validation_accuracy(network, &val_inputs, &val_targets) * 100.0);
+    }
+
+    Ok(())
+}
+
+/// Classification multi-classe de la sévérité motrice (softmax + entropie croisée catégorielle)
+fn classify_updrs_severity(dataset: &ParkinsonDataset) -> Result<(), Box<dyn std::error::Error>> {
+    println!("\n🎚️  SÉVÉRITÉ MOTRICE (UPDRS < 15, 15-25, ≥ 25):");
+
+    // Validation pour l'arrêt précoce, test pour le score final
+    let mut manifest = SplitManifest::by_subject(dataset, DatasetSource::Regression, 0.2, 0.2, 7);
+    let (train_inputs, train_scores) = manifest.train_set(dataset)?;
+    let (val_inputs, val_scores) = manifest.validation_set(dataset)?;
+    let train_targets = severity_targets(&train_scores, &UPDRS_SEVERITY_BINS);
+    let val_targets = severity_targets(&val_scores, &UPDRS_SEVERITY_BINS);
+    let classes = UPDRS_SEVERITY_BINS.len() + 1;
+
+    let mut network = NeuralNetwork::new(0.05);
+    network
+        .add_layer(16, 32, Activation::Relu)
+        .add_layer(32, 16, Activation::Relu)
+        .add_layer(16, classes, Activation::Softmax);
+
+    let config = TrainingConfig {
+        epochs: 60,
+        loss: Some(LossKind::CategoricalCrossEntropy),
+        early_stopping: Some(EarlyStopping { min_delta: 1e-4, ..EarlyStopping::new(ValidationMetric::Loss, 10) }),
+        ..TrainingConfig::fast()
+    };
+    let validation = Some((val_inputs.as_slice(), val_targets.as_slice()));
+    Trainer::new(config).fit(&mut network, &train_inputs, &train_targets, validation);
+
+    manifest.unlock_test();
+    let (test_inputs, test_scores) = manifest.test_set(dataset)?;
+
+    // Matrice de confusion: lignes = classe réelle, colonnes = classe prédite
+    let mut confusion = vec![vec![0usize; classes]; classes];
+    for (input, score) in test_inputs.iter().zip(&test_scores) {
+        confusion[severity_class(score[0] * 100.0, &UPDRS_SEVERITY_BINS)][network.predict_class(input)] += 1;
+    }
+    let correct: usize = (0..classes).map(|class| confusion[class][class]).sum();
+    println!("   Accuracy test: {:.1}% sur {} enregistrements",
+        correct as f64 / test_inputs.len().max(1) as f64 * 100.0, test_inputs.len());
+    for (class, row) in confusion.iter().enumerate() {
+        println!("   réelle {}: {:?}", class, row);
+    }
+
+    Ok(())
+}
+
+fn validation_accuracy(network: &NeuralNetwork, inputs: &[Array1<f64>], targets: &[Array1<f64>]) -> f64 {
+    let correct = inputs.iter().zip(targets)
+        .filter(|(input, target)| (network.forward(input)[0] > 0.5) == (target[0] > 0.5))
+        .count();
+    correct as f64 / inputs.len().max(1) as f64
+}
+
 fn generate_corrected_report(
     class_metrics: &TrainingMetrics, 
     reg_metrics: &TrainingMetrics, 
@@ -1,6 +1,13 @@
-use ndarray::Array1;
+use ndarray::{Array1, Array2};
 use serde::{Deserialize, Serialize};
 
+/// Constantes de SELU (réseaux auto-normalisés)
+const SELU_LAMBDA: f64 = 1.050_700_987_355_480_5;
+const SELU_ALPHA: f64 = 1.673_263_242_354_377_3;
+
+/// √(2/π), pour l'approximation tanh de GELU
+const GELU_SCALE: f64 = 0.797_884_560_802_865_4;
+
 /// Fonctions d'activation pour les réseaux neuronaux
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum Activation {
@@ -9,6 +16,22 @@ pub enum Activation {
     Tanh,
     Linear,
     Softmax,
+    /// x si x > 0, αx sinon
+    LeakyRelu { alpha: f64 },
+    /// x si x > 0, α(eˣ − 1) sinon
+    Elu { alpha: f64 },
+    /// ELU mise à l'échelle, à initialiser en LeCun
+    Selu,
+    /// x·Φ(x), approximation tanh
+    Gelu,
+    /// Swish / SiLU: x·σ(x)
+    Swish,
+    /// x·tanh(softplus(x))
+    Mish,
+    /// ln(1 + eˣ)
+    Softplus,
+    /// LeakyReLU dont les pentes (une par neurone) sont apprises
+    Prelu { slopes: Vec<f64> },
 }
 
 impl Activation {
@@ -20,10 +43,23 @@ impl Activation {
             Self::Tanh => self.tanh(x),
             Self::Linear => x.clone(),
             Self::Softmax => self.softmax(x),
+            &Self::LeakyRelu { alpha } => x.mapv(|v| if v > 0.0 { v } else { alpha * v }),
+            &Self::Elu { alpha } => x.mapv(|v| if v > 0.0 { v } else { alpha * v.exp_m1() }),
+            Self::Selu => x.mapv(|v| SELU_LAMBDA * if v > 0.0 { v } else { SELU_ALPHA * v.exp_m1() }),
+            Self::Gelu => x.mapv(|v| 0.5 * v * (1.0 + gelu_tanh(v))),
+            Self::Swish => x.mapv(|v| v * sigmoid(v)),
+            Self::Mish => x.mapv(|v| v * softplus(v).tanh()),
+            Self::Softplus => x.mapv(softplus),
+            Self::Prelu { slopes } => x.iter().enumerate()
+                .map(|(i, &v)| if v > 0.0 { v } else { slope(slopes, i) * v })
+                .collect(),
         }
     }
 
-    /// Calcule la dérivée de la fonction d'activation
+    /// Calcule la dérivée de la fonction d'activation, élément par élément
+    ///
+    /// Pour softmax, seule la diagonale du jacobien est retournée: la rétropropagation
+    /// passe par `backward`.
     pub fn derivative(&self, x: &Array1<f64>) -> Array1<f64> {
         match self {
             Self::Relu => self.relu_derivative(x),
@@ -37,7 +73,126 @@ impl Activation {
             }
             Self::Linear => Array1::ones(x.len()),
             Self::Softmax => self.softmax_derivative(x),
+            &Self::LeakyRelu { alpha } => x.mapv(|v| if v > 0.0 { 1.0 } else { alpha }),
+            &Self::Elu { alpha } => x.mapv(|v| if v > 0.0 { 1.0 } else { alpha * v.exp() }),
+            Self::Selu => x.mapv(|v| SELU_LAMBDA * if v > 0.0 { 1.0 } else { SELU_ALPHA * v.exp() }),
+            Self::Gelu => x.mapv(|v| {
+                let t = gelu_tanh(v);
+                0.5 * (1.0 + t) + 0.5 * v * (1.0 - t * t) * GELU_SCALE * (1.0 + 3.0 * 0.044715 * v * v)
+            }),
+            Self::Swish => x.mapv(|v| {
+                let s = sigmoid(v);
+                s + v * s * (1.0 - s)
+            }),
+            Self::Mish => x.mapv(|v| {
+                let t = softplus(v).tanh();
+                t + v * (1.0 - t * t) * sigmoid(v)
+            }),
+            Self::Softplus => x.mapv(sigmoid),
+            Self::Prelu { slopes } => x.iter().enumerate()
+                .map(|(i, &v)| if v > 0.0 { 1.0 } else { slope(slopes, i) })
+                .collect(),
+        }
+    }
+
+    /// Rétropropage un gradient par rapport à la sortie de l'activation (produit par le jacobien)
+    pub fn backward(&self, x: &Array1<f64>, output_delta: &Array1<f64>) -> Array1<f64> {
+        match self {
+            Self::Softmax => {
+                // J = diag(s) − s·sᵀ, d'où Jᵀδ = s ⊙ (δ − s·δ)
+                let softmax = self.softmax(x);
+                let projection = softmax.dot(output_delta);
+                &softmax * &(output_delta - projection)
+            }
+            _ => output_delta * &self.derivative(x),
+        }
+    }
+
+    /// PReLU avec une pente initiale de 0.25, étendue à chaque neurone par la couche
+    pub fn prelu() -> Self {
+        Self::Prelu { slopes: vec![0.25] }
+    }
+
+    /// Paramètres entraînables de l'activation (pentes PReLU)
+    pub fn parameters(&self) -> &[f64] {
+        match self {
+            Self::Prelu { slopes } => slopes,
+            _ => &[],
+        }
+    }
+
+    /// Paramètres entraînables modifiables
+    pub fn parameters_mut(&mut self) -> &mut [f64] {
+        match self {
+            Self::Prelu { slopes } => slopes,
+            _ => &mut [],
+        }
+    }
+
+    /// Gradient des pentes PReLU
+    ///
+    /// `output_delta` est le gradient par rapport à la sortie de l'activation, avant
+    /// `backward`: ∂y/∂a = x pour x ≤ 0.
+    pub fn parameter_gradient(&self, x: &Array1<f64>, output_delta: &Array1<f64>) -> Array1<f64> {
+        let Self::Prelu { slopes } = self else { return Array1::zeros(0) };
+        let mut gradient = Array1::zeros(slopes.len());
+        for (i, (&v, &d)) in x.iter().zip(output_delta).enumerate() {
+            if v <= 0.0 {
+                gradient[i.min(slopes.len() - 1)] += d * v;
+            }
+        }
+        gradient
+    }
+
+    /// Applique l'activation à chaque ligne d'un batch
+    pub fn activate_rows(&self, x: &Array2<f64>) -> Array2<f64> {
+        self.map_rows(x, Self::activate)
+    }
+
+    /// Dérivée de l'activation pour chaque ligne d'un batch
+    pub fn derivative_rows(&self, x: &Array2<f64>) -> Array2<f64> {
+        self.map_rows(x, Self::derivative)
+    }
+
+    /// Rétropropagation de chaque ligne d'un batch
+    pub fn backward_rows(&self, x: &Array2<f64>, output_delta: &Array2<f64>) -> Array2<f64> {
+        match self {
+            Self::Softmax => {
+                let mut delta = Array2::zeros(x.dim());
+                for ((mut out, row), delta_row) in delta.rows_mut().into_iter().zip(x.rows()).zip(output_delta.rows()) {
+                    out.assign(&self.backward(&row.to_owned(), &delta_row.to_owned()));
+                }
+                delta
+            }
+            _ => output_delta * &self.derivative_rows(x),
+        }
+    }
+
+    /// Applique `f` ligne par ligne, ou en une passe sur la matrice aplatie quand la
+    /// fonction ne dépend pas de la position dans la ligne
+    fn map_rows(&self, x: &Array2<f64>, f: fn(&Self, &Array1<f64>) -> Array1<f64>) -> Array2<f64> {
+        if !matches!(self, Self::Softmax | Self::Prelu { .. }) {
+            let flat: Array1<f64> = x.iter().copied().collect();
+            return f(self, &flat).into_shape_with_order(x.dim()).expect("même nombre d'éléments");
+        }
+
+        let mut output = Array2::zeros(x.dim());
+        for (mut out, row) in output.rows_mut().into_iter().zip(x.rows()) {
+            out.assign(&f(self, &row.to_owned()));
+        }
+        output
+    }
+
+    /// Gradient des pentes PReLU sommé sur les lignes d'un batch
+    pub fn parameter_gradient_rows(&self, x: &Array2<f64>, output_delta: &Array2<f64>) -> Array1<f64> {
+        let mut gradient = Array1::zeros(self.parameters().len());
+        if gradient.is_empty() {
+            return gradient;
+        }
+        for (row, delta_row) in x.rows().into_iter().zip(output_delta.rows()) {
+            gradient += &self.parameter_gradient(&row.to_owned(), &delta_row.to_owned());
         }
+        gradient
     }
 
     /// Fonction ReLU: max(0, x)
@@ -68,9 +223,27 @@ impl Activation {
         exp / sum
     }
 
-    /// Dérivée de softmax
+    /// Diagonale du jacobien de softmax
     fn softmax_derivative(&self, x: &Array1<f64>) -> Array1<f64> {
         let softmax = self.softmax(x);
         &softmax * &(1.0 - &softmax)
     }
-}
\ No newline at end of file
+}
+
+/// Pente du neurone `i` (une pente partagée si une seule est définie)
+fn slope(slopes: &[f64], i: usize) -> f64 {
+    slopes[i.min(slopes.len() - 1)]
+}
+
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// ln(1 + eˣ) sans débordement
+fn softplus(x: f64) -> f64 {
+    x.max(0.0) + (-x.abs()).exp().ln_1p()
+}
+
+fn gelu_tanh(x: f64) -> f64 {
+    (GELU_SCALE * (x + 0.044715 * x.powi(3))).tanh()
+}
@@ -0,0 +1,436 @@
+use std::fs;
+use std::path::Path;
+
+use ndarray::{Array1, Array2};
+use crate::data::preprocessing::MinMaxScaler;
+use super::{Activation, Initializer, Layer, NeuralNetwork, Normalization, NormalizationKind};
+
+// Format binaire des modèles pour l'inférence
+//
+//   magic "PKNN" | endianness u8 | version u16 | précision u8 | réservé u8
+//   learning_rate f64 | nb couches u32
+//   table des formes : (entrées u32, sorties u32, activation u8, paramètre d'activation f64,
+//                      normalisation u8) par couche, suivie de (epsilon f64, momentum f64)
+//                      si la couche est normalisée
+//   charge utile : poids (ligne par ligne), biais, γ, β et moyennes mobiles (BatchNorm),
+//                  puis pentes PReLU de chaque couche, en f64 ou f32
+//   features : nb u32 puis (longueur u32, octets UTF-8) par nom
+//   scaler : présent u8, puis nb u32, mins et maxs en f64
+//   CRC-32 (IEEE) u32 de tout ce qui précède
+//
+// Les entiers et flottants sont écrits dans l'ordre d'octets annoncé par l'en-tête.
+// Les tailles lues ne sont jamais crues sur parole : chaque tableau est borné par les
+// octets restants et les formes doivent s'enchaîner d'une couche à la suivante.
+
+/// Signature des fichiers de modèle binaires
+pub const BINARY_MAGIC: &[u8; 4] = b"PKNN";
+
+/// Version courante du format binaire
+pub const BINARY_FORMAT_VERSION: u16 = 1;
+
+const LITTLE_ENDIAN: u8 = 1;
+const BIG_ENDIAN: u8 = 2;
+
+/// Précision des poids dans la charge utile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precision {
+    F64,
+    /// Moitié moins volumineux, suffisant pour l'inférence
+    F32,
+}
+
+impl Precision {
+    fn code(&self) -> u8 {
+        match self {
+            Self::F64 => 0,
+            Self::F32 => 1,
+        }
+    }
+
+    fn from_code(code: u8) -> Result<Self, Box<dyn std::error::Error>> {
+        match code {
+            0 => Ok(Self::F64),
+            1 => Ok(Self::F32),
+            _ => Err(format!("précision inconnue: {}", code).into()),
+        }
+    }
+}
+
+impl NeuralNetwork {
+    /// Encode le modèle au format binaire
+    pub fn to_bytes(&self, precision: Precision) -> Vec<u8> {
+        let mut writer = Writer::new(cfg!(target_endian = "big"));
+
+        writer.bytes.extend_from_slice(BINARY_MAGIC);
+        writer.bytes.push(if writer.big_endian { BIG_ENDIAN } else { LITTLE_ENDIAN });
+        writer.u16(BINARY_FORMAT_VERSION);
+        writer.bytes.push(precision.code());
+        writer.bytes.push(0);
+        writer.f64(self.learning_rate);
+        writer.u32(self.layers.len() as u32);
+
+        for layer in &self.layers {
+            writer.u32(layer.input_size as u32);
+            writer.u32(layer.output_size as u32);
+            let (code, parameter) = activation_code(&layer.activation);
+            writer.bytes.push(code);
+            writer.f64(parameter);
+            match &layer.normalization {
+                Some(normalization) => {
+                    let (code, momentum) = match normalization.kind {
+                        NormalizationKind::Batch { momentum } => (1, momentum),
+                        NormalizationKind::Layer => (2, 0.0),
+                    };
+                    writer.bytes.push(code);
+                    writer.f64(normalization.epsilon);
+                    writer.f64(momentum);
+                }
+                None => writer.bytes.push(0),
+            }
+        }
+
+        for layer in &self.layers {
+            let mut values: Vec<f64> = layer.weights.iter().chain(layer.biases.iter()).copied().collect();
+            if let Some(normalization) = &layer.normalization {
+                values.extend(normalization.gamma.iter().chain(normalization.beta.iter()));
+                if let NormalizationKind::Batch { .. } = normalization.kind {
+                    values.extend(normalization.running_mean.iter().chain(normalization.running_var.iter()));
+                }
+            }
+            values.extend(layer.activation.parameters());
+            for value in values {
+                match precision {
+                    Precision::F64 => writer.f64(value),
+                    Precision::F32 => writer.f32(value as f32),
+                }
+            }
+        }
+
+        writer.u32(self.feature_names.len() as u32);
+        for name in &self.feature_names {
+            writer.u32(name.len() as u32);
+            writer.bytes.extend_from_slice(name.as_bytes());
+        }
+
+        match &self.scaler {
+            Some(scaler) => {
+                writer.bytes.push(1);
+                writer.u32(scaler.mins.len() as u32);
+                for &value in scaler.mins.iter().chain(scaler.maxs.iter()) {
+                    writer.f64(value);
+                }
+            }
+            None => writer.bytes.push(0),
+        }
+
+        let checksum = crc32(&writer.bytes);
+        writer.u32(checksum);
+        writer.bytes
+    }
+
+    /// Décode un modèle binaire
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Box<dyn std::error::Error>> {
+        if bytes.len() < 4 + 1 + 2 + 4 || &bytes[..4] != BINARY_MAGIC {
+            return Err("fichier de modèle binaire invalide (signature absente)".into());
+        }
+
+        let big_endian = match bytes[4] {
+            LITTLE_ENDIAN => false,
+            BIG_ENDIAN => true,
+            other => return Err(format!("ordre d'octets inconnu: {}", other).into()),
+        };
+
+        let (content, checksum_bytes) = bytes.split_at(bytes.len() - 4);
+        let mut checksum_reader = Reader::new(checksum_bytes, big_endian);
+        if checksum_reader.u32()? != crc32(content) {
+            return Err("somme de contrôle invalide: fichier corrompu".into());
+        }
+
+        let mut reader = Reader::new(&content[5..], big_endian);
+        let version = reader.u16()?;
+        if version != BINARY_FORMAT_VERSION {
+            return Err(format!(
+                "format binaire {} non supporté (version {} attendue)", version, BINARY_FORMAT_VERSION
+            ).into());
+        }
+        decode(&mut reader)
+    }
+
+    /// Sauvegarde le modèle au format binaire
+    pub fn save_binary<P: AsRef<Path>>(&self, path: P, precision: Precision) -> Result<(), Box<dyn std::error::Error>> {
+        fs::write(path, self.to_bytes(precision))?;
+        Ok(())
+    }
+
+    /// Recharge un modèle binaire
+    pub fn load_binary<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::from_bytes(&fs::read(path)?)
+    }
+}
+
+/// Décode l'en-tête de précision, la table des formes et la charge utile
+fn decode(reader: &mut Reader) -> Result<NeuralNetwork, Box<dyn std::error::Error>> {
+    let precision = Precision::from_code(reader.u8()?)?;
+    reader.u8()?;
+    let learning_rate = reader.f64()?;
+    let layer_count = reader.u32()? as usize;
+
+    let mut shapes = Vec::new();
+    for _ in 0..layer_count {
+        let input_size = reader.u32()? as usize;
+        let output_size = reader.u32()? as usize;
+        if let Some((_, previous_output, _, _)) = shapes.last()
+            && *previous_output != input_size
+        {
+            return Err(format!(
+                "couche {} : {} entrées pour {} sorties de la couche précédente",
+                shapes.len(), input_size, previous_output
+            ).into());
+        }
+        // Chaque sortie a au moins un biais dans la charge utile : borne avant d'allouer les pentes
+        if output_size > reader.remaining() {
+            return Err("fichier de modèle binaire tronqué".into());
+        }
+        let code = reader.u8()?;
+        let parameter = reader.f64()?;
+        let activation = activation_from_code(code, parameter, output_size)?;
+        let normalization = match reader.u8()? {
+            0 => None,
+            code => {
+                let epsilon = reader.f64()?;
+                let momentum = reader.f64()?;
+                let kind = match code {
+                    1 => NormalizationKind::Batch { momentum },
+                    2 => NormalizationKind::Layer,
+                    _ => return Err(format!("normalisation inconnue: {}", code).into()),
+                };
+                Some((kind, epsilon))
+            }
+        };
+        shapes.push((input_size, output_size, activation, normalization));
+    }
+
+    let mut layers = Vec::new();
+    for (input_size, output_size, activation, normalization) in shapes {
+        let mut layer = read_layer(reader, input_size, output_size, activation, precision)?;
+        if let Some((kind, epsilon)) = normalization {
+            let mut norm = Normalization::new(kind, output_size);
+            norm.epsilon = epsilon;
+            norm.gamma = Array1::from_vec(reader.values(output_size, precision)?);
+            norm.beta = Array1::from_vec(reader.values(output_size, precision)?);
+            if let NormalizationKind::Batch { .. } = kind {
+                norm.running_mean = Array1::from_vec(reader.values(output_size, precision)?);
+                norm.running_var = Array1::from_vec(reader.values(output_size, precision)?);
+            }
+            layer.normalization = Some(norm);
+        }
+        let slopes = layer.activation.parameters_mut();
+        let values = reader.values(slopes.len(), precision)?;
+        slopes.copy_from_slice(&values);
+        layers.push(layer);
+    }
+
+    decode_metadata(reader, learning_rate, layers)
+}
+
+/// Poids et biais d'une couche
+fn read_layer(
+    reader: &mut Reader,
+    input_size: usize,
+    output_size: usize,
+    activation: Activation,
+    precision: Precision,
+) -> Result<Layer, Box<dyn std::error::Error>> {
+    let weight_count = input_size.checked_mul(output_size)
+        .ok_or("fichier de modèle binaire invalide (couche trop grande)")?;
+    let weights = reader.values(weight_count, precision)?;
+    let biases = reader.values(output_size, precision)?;
+    let mut layer = Layer::with_initializer(input_size, output_size, activation, Initializer::Zeros);
+    layer.weights = Array2::from_shape_vec((output_size, input_size), weights)?;
+    layer.biases = Array1::from_vec(biases);
+    Ok(layer)
+}
+
+/// Noms des features et scaler
+fn decode_metadata(
+    reader: &mut Reader,
+    learning_rate: f64,
+    layers: Vec<Layer>,
+) -> Result<NeuralNetwork, Box<dyn std::error::Error>> {
+    let name_count = reader.u32()? as usize;
+    let mut feature_names = Vec::new();
+    for _ in 0..name_count {
+        let length = reader.u32()? as usize;
+        feature_names.push(String::from_utf8(reader.take(length)?.to_vec())?);
+    }
+
+    let scaler = if reader.u8()? == 1 {
+        let count = reader.u32()? as usize;
+        let mins = reader.values(count, Precision::F64)?;
+        let maxs = reader.values(count, Precision::F64)?;
+        Some(MinMaxScaler { mins, maxs })
+    } else {
+        None
+    };
+
+    let mut network = NeuralNetwork::new(learning_rate);
+    network.layers = layers;
+    network.feature_names = feature_names;
+    network.scaler = scaler;
+    Ok(network)
+}
+
+/// Code de l'activation et son paramètre scalaire (α de LeakyReLU et ELU)
+fn activation_code(activation: &Activation) -> (u8, f64) {
+    match activation {
+        Activation::Relu => (0, 0.0),
+        Activation::Sigmoid => (1, 0.0),
+        Activation::Tanh => (2, 0.0),
+        Activation::Linear => (3, 0.0),
+        Activation::Softmax => (4, 0.0),
+        Activation::LeakyRelu { alpha } => (5, *alpha),
+        Activation::Elu { alpha } => (6, *alpha),
+        Activation::Selu => (7, 0.0),
+        Activation::Gelu => (8, 0.0),
+        Activation::Swish => (9, 0.0),
+        Activation::Mish => (10, 0.0),
+        Activation::Softplus => (11, 0.0),
+        Activation::Prelu { .. } => (12, 0.0),
+    }
+}
+
+/// Activation d'une couche de `size` neurones (pentes PReLU lues dans la charge utile)
+fn activation_from_code(code: u8, parameter: f64, size: usize) -> Result<Activation, Box<dyn std::error::Error>> {
+    match code {
+        0 => Ok(Activation::Relu),
+        1 => Ok(Activation::Sigmoid),
+        2 => Ok(Activation::Tanh),
+        3 => Ok(Activation::Linear),
+        4 => Ok(Activation::Softmax),
+        5 => Ok(Activation::LeakyRelu { alpha: parameter }),
+        6 => Ok(Activation::Elu { alpha: parameter }),
+        7 => Ok(Activation::Selu),
+        8 => Ok(Activation::Gelu),
+        9 => Ok(Activation::Swish),
+        10 => Ok(Activation::Mish),
+        11 => Ok(Activation::Softplus),
+        12 if size == 0 => Err("activation PReLU sans pente (couche vide)".into()),
+        12 => Ok(Activation::Prelu { slopes: vec![0.0; size] }),
+        _ => Err(format!("activation inconnue: {}", code).into()),
+    }
+}
+
+struct Writer {
+    bytes: Vec<u8>,
+    big_endian: bool,
+}
+
+impl Writer {
+    fn new(big_endian: bool) -> Self {
+        Self { bytes: Vec::new(), big_endian }
+    }
+
+    fn u16(&mut self, value: u16) {
+        let bytes = if self.big_endian { value.to_be_bytes() } else { value.to_le_bytes() };
+        self.bytes.extend_from_slice(&bytes);
+    }
+
+    fn u32(&mut self, value: u32) {
+        let bytes = if self.big_endian { value.to_be_bytes() } else { value.to_le_bytes() };
+        self.bytes.extend_from_slice(&bytes);
+    }
+
+    fn f32(&mut self, value: f32) {
+        self.u32(value.to_bits());
+    }
+
+    fn f64(&mut self, value: f64) {
+        let bits = value.to_bits();
+        let bytes = if self.big_endian { bits.to_be_bytes() } else { bits.to_le_bytes() };
+        self.bytes.extend_from_slice(&bytes);
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    big_endian: bool,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8], big_endian: bool) -> Self {
+        Self { bytes, position: 0, big_endian }
+    }
+
+    fn take(&mut self, count: usize) -> Result<&'a [u8], Box<dyn std::error::Error>> {
+        let end = self.position.checked_add(count)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or("fichier de modèle binaire tronqué")?;
+        let slice = &self.bytes[self.position..end];
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    fn u8(&mut self) -> Result<u8, Box<dyn std::error::Error>> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, Box<dyn std::error::Error>> {
+        let bytes: [u8; 2] = self.take(2)?.try_into()?;
+        Ok(if self.big_endian { u16::from_be_bytes(bytes) } else { u16::from_le_bytes(bytes) })
+    }
+
+    fn u32(&mut self) -> Result<u32, Box<dyn std::error::Error>> {
+        let bytes: [u8; 4] = self.take(4)?.try_into()?;
+        Ok(if self.big_endian { u32::from_be_bytes(bytes) } else { u32::from_le_bytes(bytes) })
+    }
+
+    fn f64(&mut self) -> Result<f64, Box<dyn std::error::Error>> {
+        let bytes: [u8; 8] = self.take(8)?.try_into()?;
+        Ok(f64::from_bits(if self.big_endian { u64::from_be_bytes(bytes) } else { u64::from_le_bytes(bytes) }))
+    }
+
+    /// Lit `count` flottants ; la taille est vérifiée contre les octets restants avant
+    /// toute allocation
+    fn values(&mut self, count: usize, precision: Precision) -> Result<Vec<f64>, Box<dyn std::error::Error>> {
+        let width = match precision {
+            Precision::F64 => 8,
+            Precision::F32 => 4,
+        };
+        let length = count.checked_mul(width).ok_or("fichier de modèle binaire tronqué")?;
+        let mut chunk = Reader::new(self.take(length)?, self.big_endian);
+        (0..count)
+            .map(|_| match precision {
+                Precision::F64 => chunk.f64(),
+                Precision::F32 => Ok(f32::from_bits(chunk.u32()?) as f64),
+            })
+            .collect()
+    }
+}
+
+/// Table du CRC-32 IEEE (polynôme réfléchi 0xEDB88320)
+const CRC_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0u32, |crc, &byte| {
+        CRC_TABLE[((crc ^ byte as u32) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
@@ -0,0 +1,72 @@
+use std::ops::ControlFlow;
+use std::path::PathBuf;
+use super::NeuralNetwork;
+
+/// Valeurs d'une epoch terminée, transmises aux callbacks
+///
+/// L'historique complet reste accessible par `NeuralNetwork::metrics`.
+#[derive(Debug, Clone, Copy)]
+pub struct EpochSummary {
+    pub epoch: usize,
+    /// Perte moyenne des batches, pénalités comprises
+    pub loss: f64,
+    /// Métrique de validation surveillée (perte de validation sans arrêt précoce), si
+    /// `fit` a reçu un jeu de validation
+    pub validation: Option<f64>,
+    /// Taux d'apprentissage de l'epoch suivante
+    pub learning_rate: f64,
+    /// Vrai si la perte est la meilleure de l'entraînement
+    pub improved: bool,
+}
+
+/// Valeurs d'un batch après le pas d'optimisation
+#[derive(Debug, Clone, Copy)]
+pub struct BatchSummary {
+    pub epoch: usize,
+    /// Indice du batch dans l'epoch
+    pub batch: usize,
+    pub size: usize,
+    pub loss: f64,
+}
+
+/// Points d'extension de `Trainer::fit`
+///
+/// Toutes les méthodes ont une implémentation vide; le réseau est passé en mutable pour
+/// permettre sauvegarde, restauration de poids ou changement de mode.
+pub trait Callback {
+    fn on_train_begin(&mut self, _network: &mut NeuralNetwork) {}
+
+    fn on_epoch_begin(&mut self, _epoch: usize, _network: &mut NeuralNetwork) {}
+
+    fn on_batch_end(&mut self, _batch: &BatchSummary, _network: &mut NeuralNetwork) {}
+
+    /// `ControlFlow::Break` arrête l'entraînement après cette epoch
+    fn on_epoch_end(&mut self, _epoch: &EpochSummary, _network: &mut NeuralNetwork) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+
+    fn on_train_end(&mut self, _network: &mut NeuralNetwork) {}
+}
+
+/// Sauvegarde le modèle en JSON à chaque amélioration de la perte
+pub struct Checkpoint {
+    pub path: PathBuf,
+}
+
+impl Checkpoint {
+    /// Crée une sauvegarde vers `path`
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+}
+
+impl Callback for Checkpoint {
+    fn on_epoch_end(&mut self, epoch: &EpochSummary, network: &mut NeuralNetwork) -> ControlFlow<()> {
+        if epoch.improved
+            && let Err(err) = network.save(&self.path)
+        {
+            println!("⚠️  Sauvegarde de {} impossible: {}", self.path.display(), err);
+        }
+        ControlFlow::Continue(())
+    }
+}
@@ -0,0 +1,62 @@
+use ndarray::Array1;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Constante α' = −λ·α de SELU, valeur de saturation vers laquelle l'alpha-dropout
+/// ramène les neurones éteints
+const SELU_SATURATION: f64 = -1.758_099_340_847_376_6;
+
+/// Variante de dropout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DropoutKind {
+    /// Met les neurones éteints à zéro et remet les autres à l'échelle 1 / (1 − p)
+    Standard,
+    /// Préserve moyenne et variance des activations SELU (réseaux auto-normalisés)
+    Alpha,
+}
+
+/// Dropout appliqué à la sortie d'une couche, actif uniquement en mode entraînement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dropout {
+    /// Probabilité d'éteindre chaque neurone
+    pub rate: f64,
+    pub kind: DropoutKind,
+}
+
+impl Dropout {
+    /// Dropout inversé classique
+    pub fn new(rate: f64) -> Self {
+        Self { rate: rate.clamp(0.0, 0.99), kind: DropoutKind::Standard }
+    }
+
+    /// Alpha-dropout pour les couches SELU
+    pub fn alpha(rate: f64) -> Self {
+        Self { rate: rate.clamp(0.0, 0.99), kind: DropoutKind::Alpha }
+    }
+
+    /// Tire un masque et l'applique
+    ///
+    /// Retourne la sortie et le multiplicateur à appliquer au gradient lors de la
+    /// rétropropagation (dérivée de la sortie par rapport à l'entrée).
+    pub fn apply<R: Rng>(&self, x: &Array1<f64>, rng: &mut R) -> (Array1<f64>, Array1<f64>) {
+        let keep = 1.0 - self.rate;
+        let kept: Vec<bool> = (0..x.len()).map(|_| rng.random::<f64>() < keep).collect();
+
+        match self.kind {
+            DropoutKind::Standard => {
+                let mask: Array1<f64> = kept.iter().map(|&k| if k { 1.0 / keep } else { 0.0 }).collect();
+                (x * &mask, mask)
+            }
+            DropoutKind::Alpha => {
+                // y = a·(x·m + α'·(1 − m)) + b, avec a et b choisis pour conserver moyenne et variance
+                let a = (keep + SELU_SATURATION.powi(2) * keep * self.rate).powf(-0.5);
+                let b = -a * SELU_SATURATION * self.rate;
+                let output = x.iter().zip(&kept)
+                    .map(|(&v, &k)| a * if k { v } else { SELU_SATURATION } + b)
+                    .collect();
+                let mask = kept.iter().map(|&k| if k { a } else { 0.0 }).collect();
+                (output, mask)
+            }
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use ndarray::{Array1, Array2};
+use super::{Layer, Loss};
+
+/// Métrique de validation surveillée par l'arrêt précoce
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValidationMetric {
+    /// Perte de validation (non pondérée)
+    Loss,
+    /// Aire sous la courbe ROC de la première sortie (cible positive si > 0.5)
+    Auc,
+    /// Erreur absolue moyenne sur toutes les sorties
+    Mae,
+}
+
+impl ValidationMetric {
+    /// Vrai si une valeur plus grande est meilleure
+    pub fn higher_is_better(&self) -> bool {
+        matches!(self, Self::Auc)
+    }
+
+    /// Nom court pour l'affichage
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Loss => "loss",
+            Self::Auc => "AUC",
+            Self::Mae => "MAE",
+        }
+    }
+
+    /// Valeur de la métrique à partir des logits et sorties (une ligne par échantillon)
+    pub fn evaluate(&self, loss: &dyn Loss, logits: &Array2<f64>, outputs: &Array2<f64>, targets: &[Array1<f64>]) -> f64 {
+        let count = targets.len().max(1) as f64;
+        match self {
+            Self::Loss => {
+                logits.rows().into_iter().zip(outputs.rows()).zip(targets)
+                    .map(|((z, output), target)| loss.value(&z.to_owned(), &output.to_owned(), target))
+                    .sum::<f64>() / count
+            }
+            Self::Auc => {
+                let scores: Vec<f64> = outputs.column(0).to_vec();
+                let positives: Vec<bool> = targets.iter().map(|target| target[0] > 0.5).collect();
+                roc_auc(&scores, &positives)
+            }
+            Self::Mae => {
+                outputs.rows().into_iter().zip(targets)
+                    .map(|(output, target)| (&output - target).mapv(f64::abs).mean().unwrap_or(0.0))
+                    .sum::<f64>() / count
+            }
+        }
+    }
+}
+
+/// Arrêt sur une métrique de validation, avec retour aux poids de la meilleure epoch
+#[derive(Debug, Clone)]
+pub struct EarlyStopping {
+    pub metric: ValidationMetric,
+    /// Arrêt après plus de `patience` epochs sans amélioration
+    pub patience: usize,
+    /// Amélioration minimale pour remettre la patience à zéro
+    pub min_delta: f64,
+    /// Recharge à la fin les poids (et moyennes mobiles) de la meilleure epoch
+    pub restore_best_weights: bool,
+}
+
+impl EarlyStopping {
+    /// Arrêt sur `metric`, sans seuil d'amélioration, meilleurs poids restaurés
+    pub fn new(metric: ValidationMetric, patience: usize) -> Self {
+        Self { metric, patience, min_delta: 0.0, restore_best_weights: true }
+    }
+
+    /// Vrai si `value` améliore `best` d'au moins `min_delta`
+    pub fn improves(&self, value: f64, best: f64) -> bool {
+        if self.metric.higher_is_better() {
+            value > best + self.min_delta
+        } else {
+            value < best - self.min_delta
+        }
+    }
+}
+
+impl Default for EarlyStopping {
+    fn default() -> Self {
+        Self::new(ValidationMetric::Loss, 20)
+    }
+}
+
+/// Meilleure epoch observée pendant un entraînement
+pub(super) struct BestEpoch {
+    pub epoch: usize,
+    pub value: f64,
+    pub layers: Vec<Layer>,
+    /// Epochs écoulées depuis
+    pub wait: usize,
+}
+
+impl BestEpoch {
+    /// Prend en compte une epoch; retourne vrai s'il faut s'arrêter
+    pub fn update(best: &mut Option<Self>, config: &EarlyStopping, epoch: usize, value: f64, layers: &[Layer]) -> bool {
+        match best {
+            Some(current) if !config.improves(value, current.value) => {
+                current.wait += 1;
+                current.wait > config.patience
+            }
+            _ => {
+                *best = Some(Self { epoch, value, layers: layers.to_vec(), wait: 0 });
+                false
+            }
+        }
+    }
+}
+
+/// Aire sous la courbe ROC par les rangs (statistique de Mann-Whitney), ex aequo moyennés;
+/// 0.5 si une classe est absente
+fn roc_auc(scores: &[f64], positives: &[bool]) -> f64 {
+    let positive_count = positives.iter().filter(|&&p| p).count() as f64;
+    let negative_count = positives.len() as f64 - positive_count;
+    if positive_count == 0.0 || negative_count == 0.0 {
+        return 0.5;
+    }
+
+    let mut order: Vec<usize> = (0..scores.len()).collect();
+    order.sort_by(|&a, &b| scores[a].total_cmp(&scores[b]));
+
+    let mut positive_rank_sum = 0.0;
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start;
+        while end + 1 < order.len() && scores[order[end + 1]] == scores[order[start]] {
+            end += 1;
+        }
+        let rank = (start + end) as f64 / 2.0 + 1.0;
+        positive_rank_sum += rank * order[start..=end].iter().filter(|&&i| positives[i]).count() as f64;
+        start = end + 1;
+    }
+
+    (positive_rank_sum - positive_count * (positive_count + 1.0) / 2.0) / (positive_count * negative_count)
+}
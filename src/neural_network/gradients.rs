@@ -0,0 +1,92 @@
+use ndarray::{Array1, Array2};
+use super::Layer;
+use super::normalization::Moments;
+
+/// Moyenne et variance du batch de chaque couche à BatchNorm (`None` pour les autres)
+pub type BatchStatistics = Vec<Option<Moments>>;
+
+/// Gradients des paramètres d'une couche
+#[derive(Debug, Clone)]
+pub struct LayerGradient {
+    pub weights: Array2<f64>,
+    pub biases: Array1<f64>,
+    /// Gain et décalage de la normalisation (vides si la couche n'en a pas)
+    pub gamma: Array1<f64>,
+    pub beta: Array1<f64>,
+    /// Pentes PReLU (vides pour les autres activations)
+    pub slopes: Array1<f64>,
+}
+
+impl LayerGradient {
+    /// Gradients nuls aux dimensions de la couche
+    pub fn zeros(layer: &Layer) -> Self {
+        let normalized = layer.normalization.as_ref().map_or(0, |norm| norm.gamma.len());
+        Self {
+            weights: Array2::zeros(layer.weights.dim()),
+            biases: Array1::zeros(layer.biases.len()),
+            gamma: Array1::zeros(normalized),
+            beta: Array1::zeros(normalized),
+            slopes: Array1::zeros(layer.activation.parameters().len()),
+        }
+    }
+
+    /// Valeurs dans l'ordre de `Layer::parameters`
+    pub fn values(&self) -> impl Iterator<Item = &f64> {
+        self.weights.iter()
+            .chain(self.biases.iter())
+            .chain(self.gamma.iter())
+            .chain(self.beta.iter())
+            .chain(self.slopes.iter())
+    }
+
+    fn values_mut(&mut self) -> impl Iterator<Item = &mut f64> {
+        self.weights.iter_mut()
+            .chain(self.biases.iter_mut())
+            .chain(self.gamma.iter_mut())
+            .chain(self.beta.iter_mut())
+            .chain(self.slopes.iter_mut())
+    }
+}
+
+/// Gradients de tout le réseau pour un batch
+#[derive(Debug, Clone)]
+pub struct Gradients {
+    pub layers: Vec<LayerGradient>,
+    /// Moyenne et variance du batch de chaque BatchNorm, reportées sur ses moyennes
+    /// mobiles lors du pas d'optimisation
+    pub batch_statistics: BatchStatistics,
+}
+
+impl Gradients {
+    /// Gradients nuls aux dimensions du réseau
+    pub fn zeros(layers: &[Layer]) -> Self {
+        Self {
+            layers: layers.iter().map(LayerGradient::zeros).collect(),
+            batch_statistics: vec![None; layers.len()],
+        }
+    }
+
+    /// Valeurs aplaties dans l'ordre de `NeuralNetwork::parameters`
+    pub fn values(&self) -> impl Iterator<Item = &f64> {
+        self.layers.iter().flat_map(LayerGradient::values)
+    }
+
+    /// Ajoute les gradients d'une autre partie du batch (sans les statistiques)
+    pub fn accumulate(&mut self, other: &Gradients) {
+        for (value, &other) in self.layers.iter_mut().flat_map(LayerGradient::values_mut).zip(other.values()) {
+            *value += other;
+        }
+    }
+
+    /// Multiplie tous les gradients par `factor`
+    pub fn scale(&mut self, factor: f64) {
+        for value in self.layers.iter_mut().flat_map(LayerGradient::values_mut) {
+            *value *= factor;
+        }
+    }
+
+    /// Norme L2 globale
+    pub fn norm(&self) -> f64 {
+        self.values().map(|g| g * g).sum::<f64>().sqrt()
+    }
+}
@@ -0,0 +1,98 @@
+use ndarray::{Array1, Array2};
+use rand::Rng;
+use rand_distr::StandardNormal;
+use serde::{Deserialize, Serialize};
+use super::Activation;
+
+/// Schéma d'initialisation des poids d'une couche
+///
+/// Les variances sont choisies à partir de `fan_in` (entrées) et `fan_out` (sorties) pour
+/// conserver l'échelle des activations d'une couche à l'autre.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Initializer {
+    /// U(±√(6 / (fan_in + fan_out))), pour sigmoid et tanh
+    XavierUniform,
+    /// N(0, 2 / (fan_in + fan_out))
+    XavierNormal,
+    /// N(0, 2 / fan_in), pour ReLU
+    HeNormal,
+    /// U(±√(6 / fan_in))
+    HeUniform,
+    /// N(0, 1 / fan_in), pour SELU
+    LeCunNormal,
+    /// U(±√(3 / fan_in))
+    LeCunUniform,
+    /// Matrice (semi-)orthogonale multipliée par `gain`
+    Orthogonal { gain: f64 },
+    Zeros,
+    Constant(f64),
+}
+
+impl Initializer {
+    /// Schéma adapté à une activation
+    pub fn for_activation(activation: &Activation) -> Self {
+        match activation {
+            Activation::Relu
+            | Activation::LeakyRelu { .. }
+            | Activation::Prelu { .. }
+            | Activation::Elu { .. }
+            | Activation::Gelu
+            | Activation::Swish
+            | Activation::Mish
+            | Activation::Softplus => Self::HeNormal,
+            Activation::Selu => Self::LeCunNormal,
+            Activation::Sigmoid | Activation::Tanh | Activation::Linear | Activation::Softmax => Self::XavierUniform,
+        }
+    }
+
+    /// Tire une matrice de poids (sorties × entrées)
+    pub fn weights<R: Rng>(&self, input_size: usize, output_size: usize, rng: &mut R) -> Array2<f64> {
+        let fan_in = input_size.max(1) as f64;
+        let fan_out = output_size.max(1) as f64;
+        let shape = (output_size, input_size);
+
+        match *self {
+            Self::XavierUniform => uniform(shape, (6.0 / (fan_in + fan_out)).sqrt(), rng),
+            Self::XavierNormal => normal(shape, (2.0 / (fan_in + fan_out)).sqrt(), rng),
+            Self::HeNormal => normal(shape, (2.0 / fan_in).sqrt(), rng),
+            Self::HeUniform => uniform(shape, (6.0 / fan_in).sqrt(), rng),
+            Self::LeCunNormal => normal(shape, (1.0 / fan_in).sqrt(), rng),
+            Self::LeCunUniform => uniform(shape, (3.0 / fan_in).sqrt(), rng),
+            Self::Orthogonal { gain } => orthogonal(shape, rng) * gain,
+            Self::Zeros => Array2::zeros(shape),
+            Self::Constant(value) => Array2::from_elem(shape, value),
+        }
+    }
+}
+
+fn uniform<R: Rng>(shape: (usize, usize), limit: f64, rng: &mut R) -> Array2<f64> {
+    Array2::from_shape_fn(shape, |_| rng.random_range(-limit..=limit))
+}
+
+fn normal<R: Rng>(shape: (usize, usize), std_dev: f64, rng: &mut R) -> Array2<f64> {
+    Array2::from_shape_fn(shape, |_| std_dev * rng.sample::<f64, _>(StandardNormal))
+}
+
+/// Lignes (ou colonnes si la matrice est haute) orthonormales, par Gram-Schmidt
+/// modifié sur une matrice gaussienne
+fn orthogonal<R: Rng>(shape: (usize, usize), rng: &mut R) -> Array2<f64> {
+    let (rows, cols) = shape;
+    let tall = rows > cols;
+    let (count, dimension) = if tall { (cols, rows) } else { (rows, cols) };
+
+    let mut vectors = normal((count, dimension), 1.0, rng);
+    for i in 0..count {
+        let mut vector: Array1<f64> = vectors.row(i).to_owned();
+        for j in 0..i {
+            let basis = vectors.row(j);
+            vector -= &(&basis * basis.dot(&vector));
+        }
+        let norm = vector.dot(&vector).sqrt();
+        if norm > 1e-12 {
+            vector /= norm;
+        }
+        vectors.row_mut(i).assign(&vector);
+    }
+
+    if tall { vectors.reversed_axes() } else { vectors }
+}
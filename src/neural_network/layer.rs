@@ -1,7 +1,6 @@
-use ndarray::{Array1, Array2};
-use rand::Rng;
+use ndarray::{Array1, Array2, Axis};
 use serde::{Deserialize, Serialize};
-use super::Activation;
+use super::{Activation, Dropout, Initializer, Normalization, Regularization};
 
 /// Couche de neurones optimisée
 #[derive(Debug, Clone)]
@@ -11,19 +10,35 @@ pub struct Layer {
     pub activation: Activation,
     pub input_size: usize,
     pub output_size: usize,
+    pub regularization: Option<Regularization>,
+    /// Dropout appliqué à la sortie en mode entraînement
+    pub dropout: Option<Dropout>,
+    /// Normalisation des pré-activations (BatchNorm ou LayerNorm)
+    pub normalization: Option<Normalization>,
 }
 
 impl Layer {
-    /// Crée une nouvelle couche avec initialisation optimisée
+    /// Crée une nouvelle couche, initialisée selon son activation
     pub fn new(input_size: usize, output_size: usize, activation: Activation) -> Self {
-        let mut rng = rand::rng();
-        let std_dev = (2.0 / input_size as f64).sqrt();
-        
-        // Initialisation He optimisée
-        let weights = Array2::from_shape_fn((output_size, input_size), |_| {
-            rng.random_range(-std_dev..std_dev)
-        });
-        
+        let initializer = Initializer::for_activation(&activation);
+        Self::with_initializer(input_size, output_size, activation, initializer)
+    }
+
+    /// Crée une nouvelle couche avec un schéma d'initialisation choisi
+    pub fn with_initializer(
+        input_size: usize,
+        output_size: usize,
+        mut activation: Activation,
+        initializer: Initializer,
+    ) -> Self {
+        // Une pente PReLU unique devient une pente par neurone
+        if let Activation::Prelu { slopes } = &mut activation
+            && slopes.len() == 1
+        {
+            *slopes = vec![slopes[0]; output_size];
+        }
+
+        let weights = initializer.weights(input_size, output_size, &mut rand::rng());
         let biases = Array1::zeros(output_size);
 
         Self {
@@ -32,14 +47,41 @@ impl Layer {
             activation,
             input_size,
             output_size,
+            regularization: None,
+            dropout: None,
+            normalization: None,
         }
     }
 
-    /// Propagation avant optimisée
+    /// Propagation avant optimisée (statistiques d'inférence pour une BatchNorm)
     pub fn forward(&self, input: &Array1<f64>) -> Array1<f64> {
         let z = &self.weights.dot(input) + &self.biases;
+        let z = match &self.normalization {
+            Some(normalization) => normalization.forward(&z.insert_axis(Axis(0)), false).0.row(0).to_owned(),
+            None => z,
+        };
         self.activation.activate(&z)
     }
+
+    /// Paramètres entraînables: poids, biais, γ et β de la normalisation, puis pentes PReLU
+    pub fn parameters(&self) -> impl Iterator<Item = &f64> {
+        let normalization = self.normalization.iter()
+            .flat_map(|norm| norm.gamma.iter().chain(norm.beta.iter()));
+        self.weights.iter()
+            .chain(self.biases.iter())
+            .chain(normalization)
+            .chain(self.activation.parameters())
+    }
+
+    /// Paramètres entraînables modifiables, dans l'ordre de `parameters`
+    pub fn parameters_mut(&mut self) -> impl Iterator<Item = &mut f64> {
+        let normalization = self.normalization.iter_mut()
+            .flat_map(|Normalization { gamma, beta, .. }| gamma.iter_mut().chain(beta.iter_mut()));
+        self.weights.iter_mut()
+            .chain(self.biases.iter_mut())
+            .chain(normalization)
+            .chain(self.activation.parameters_mut())
+    }
 }
 
 // Implémentations de sérialisation pour la persistance (optionnel)
@@ -51,6 +93,12 @@ struct LayerData {
     activation: Activation,
     input_size: usize,
     output_size: usize,
+    #[serde(default)]
+    regularization: Option<Regularization>,
+    #[serde(default)]
+    dropout: Option<Dropout>,
+    #[serde(default)]
+    normalization: Option<Normalization>,
 }
 
 impl Serialize for Layer {
@@ -65,6 +113,9 @@ impl Serialize for Layer {
             activation: self.activation.clone(),
             input_size: self.input_size,
             output_size: self.output_size,
+            regularization: self.regularization.clone(),
+            dropout: self.dropout.clone(),
+            normalization: self.normalization.clone(),
         };
         data.serialize(serializer)
     }
@@ -76,6 +127,11 @@ impl<'de> Deserialize<'de> for Layer {
         D: serde::Deserializer<'de>,
     {
         let data = LayerData::deserialize(deserializer)?;
+        if let Activation::Prelu { slopes } = &data.activation
+            && slopes.is_empty()
+        {
+            return Err(serde::de::Error::custom("activation PReLU sans pente"));
+        }
         
         let weights = Array2::from_shape_vec(data.weights_shape, data.weights)
             .map_err(serde::de::Error::custom)?;
@@ -87,6 +143,9 @@ impl<'de> Deserialize<'de> for Layer {
             activation: data.activation,
             input_size: data.input_size,
             output_size: data.output_size,
+            regularization: data.regularization,
+            dropout: data.dropout,
+            normalization: data.normalization,
         })
     }
 }
\ No newline at end of file
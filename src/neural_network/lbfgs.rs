@@ -0,0 +1,196 @@
+use std::collections::VecDeque;
+
+use ndarray::Array1;
+use super::{BatchStatistics, Loss, Mode, NeuralNetwork, TrainingMetrics};
+
+/// Paramètres de l'entraînement L-BFGS plein batch
+#[derive(Debug, Clone)]
+pub struct LbfgsConfig {
+    pub max_iterations: usize,
+    /// Nombre de paires (s, y) conservées pour approcher le hessien
+    pub history_size: usize,
+    /// Arrêt quand la norme du gradient passe sous ce seuil
+    pub gradient_tolerance: f64,
+    /// Constante de décroissance suffisante (condition d'Armijo)
+    pub armijo: f64,
+    pub max_line_search_steps: usize,
+}
+
+impl Default for LbfgsConfig {
+    fn default() -> Self {
+        Self {
+            max_iterations: 200,
+            history_size: 10,
+            gradient_tolerance: 1e-6,
+            armijo: 1e-4,
+            max_line_search_steps: 20,
+        }
+    }
+}
+
+impl NeuralNetwork {
+    /// ENTRAÎNEMENT L-BFGS PLEIN BATCH (petits datasets)
+    ///
+    /// Optimise la perte choisie par `set_loss`, moyennée (et régularisée) sur tout le dataset
+    /// à chaque itération, avec une recherche linéaire par rebroussement (Armijo) le long de
+    /// la direction quasi-Newton.
+    ///
+    /// Le dropout est désactivé, l'objectif devant rester déterministe. Les BatchNorm
+    /// normalisent avec les statistiques du dataset complet; leurs moyennes mobiles reçoivent
+    /// à la fin celles des paramètres retenus.
+    ///
+    /// Panique avant la première itération si l'activation de sortie ne convient pas à la perte.
+    pub fn train_lbfgs(
+        &mut self,
+        inputs: &[Array1<f64>],
+        targets: &[Array1<f64>],
+        config: &LbfgsConfig,
+    ) -> TrainingMetrics {
+        if let Err(message) = self.check_loss_output(&self.loss) {
+            panic!("{}", message);
+        }
+        println!("🧮 Entraînement L-BFGS - {} samples, {} paramètres", inputs.len(), self.parameter_count());
+
+        let objective = self.loss.build();
+        let dropouts: Vec<_> = self.layers.iter_mut().map(|layer| layer.dropout.take()).collect();
+
+        let mut parameters = self.parameters();
+        let (mut loss, mut gradient, mut statistics) = self.full_batch_objective(objective.as_ref(), inputs, targets);
+        let mut history: VecDeque<(Vec<f64>, Vec<f64>, f64)> = VecDeque::new();
+
+        for iteration in 0..config.max_iterations {
+            let gradient_norm = dot(&gradient, &gradient).sqrt();
+            if gradient_norm < config.gradient_tolerance {
+                println!("⏹️  Gradient négligeable à l'itération {}", iteration);
+                break;
+            }
+
+            let direction = two_loop_direction(&gradient, &history);
+            let mut slope = dot(&gradient, &direction);
+            let direction = if slope < 0.0 {
+                direction
+            } else {
+                // Direction non descendante: on repart de la plus forte pente
+                history.clear();
+                slope = -gradient_norm * gradient_norm;
+                gradient.iter().map(|g| -g).collect()
+            };
+
+            // Premier pas prudent tant qu'aucune courbure n'est connue
+            let mut step = if history.is_empty() { (1.0 / gradient_norm).min(1.0) } else { 1.0 };
+            let mut accepted = None;
+            for _ in 0..config.max_line_search_steps {
+                let candidate: Vec<f64> = parameters.iter().zip(&direction)
+                    .map(|(p, d)| p + step * d)
+                    .collect();
+                self.set_parameters(&candidate);
+                let (candidate_loss, candidate_gradient, candidate_statistics) =
+                    self.full_batch_objective(objective.as_ref(), inputs, targets);
+
+                if candidate_loss <= loss + config.armijo * step * slope {
+                    accepted = Some((candidate, candidate_loss, candidate_gradient, candidate_statistics));
+                    break;
+                }
+                step *= 0.5;
+            }
+
+            let Some((candidate, candidate_loss, candidate_gradient, candidate_statistics)) = accepted else {
+                self.set_parameters(&parameters);
+                println!("⏹️  Recherche linéaire sans progrès à l'itération {}", iteration);
+                break;
+            };
+
+            let s: Vec<f64> = candidate.iter().zip(&parameters).map(|(a, b)| a - b).collect();
+            let y: Vec<f64> = candidate_gradient.iter().zip(&gradient).map(|(a, b)| a - b).collect();
+            let curvature = dot(&s, &y);
+            // Les paires sans courbure positive rendraient l'approximation indéfinie
+            if curvature > 1e-10 {
+                history.push_back((s, y, 1.0 / curvature));
+                if history.len() > config.history_size {
+                    history.pop_front();
+                }
+            }
+
+            parameters = candidate;
+            loss = candidate_loss;
+            gradient = candidate_gradient;
+            statistics = candidate_statistics;
+
+            let improved = self.metrics.update(loss, dot(&gradient, &gradient).sqrt(), step);
+            if iteration % 10 == 0 || improved && iteration < 10 {
+                let marker = if improved { "📈" } else { "  " };
+                println!("Iter  {:3} {} Loss: {:.6} | Pas: {:.4}", iteration, marker, loss, step);
+            }
+        }
+
+        for ((layer, moments), dropout) in self.layers.iter_mut().zip(statistics).zip(dropouts) {
+            if let (Some(normalization), Some((mean, var))) = (&mut layer.normalization, moments) {
+                normalization.running_mean = mean;
+                normalization.running_var = var;
+            }
+            layer.dropout = dropout;
+        }
+
+        println!("✅ Entraînement L-BFGS terminé! Best loss: {:.6}", self.metrics.best_loss);
+        self.metrics.clone()
+    }
+
+    /// Perte moyenne (plus pénalités de régularisation), son gradient aplati et les
+    /// statistiques des BatchNorm sur tout le dataset
+    ///
+    /// Les contraintes max-norm ne sont pas imposées: la projection fausserait la recherche linéaire.
+    fn full_batch_objective(
+        &self,
+        loss: &dyn Loss,
+        inputs: &[Array1<f64>],
+        targets: &[Array1<f64>],
+    ) -> (f64, Vec<f64>, BatchStatistics) {
+        let indices: Vec<usize> = (0..inputs.len()).collect();
+        let (total_loss, mut gradients) = self.accumulate_gradients(loss, inputs, targets, &indices, Mode::Training);
+
+        // `delta` dérive la perte sommée sur les sorties; on ramène au gradient de la moyenne
+        let output_size = self.layers.last().map(|layer| layer.output_size).unwrap_or(1);
+        gradients.scale(loss.gradient_scale(output_size) / inputs.len().max(1) as f64);
+        self.add_regularization_gradients(&mut gradients);
+
+        let gradient = gradients.values().copied().collect();
+        let statistics = std::mem::take(&mut gradients.batch_statistics);
+
+        (total_loss / inputs.len().max(1) as f64 + self.regularization_penalty(), gradient, statistics)
+    }
+}
+
+/// Récursion à deux boucles: direction -H·g à partir de l'historique (s, y, 1/yᵀs)
+fn two_loop_direction(gradient: &[f64], history: &VecDeque<(Vec<f64>, Vec<f64>, f64)>) -> Vec<f64> {
+    let mut q = gradient.to_vec();
+    let mut alphas = Vec::with_capacity(history.len());
+
+    for (s, y, rho) in history.iter().rev() {
+        let alpha = rho * dot(s, &q);
+        for (qi, yi) in q.iter_mut().zip(y) {
+            *qi -= alpha * yi;
+        }
+        alphas.push(alpha);
+    }
+
+    // Mise à l'échelle initiale γ = sᵀy / yᵀy
+    if let Some((s, y, _)) = history.back() {
+        let gamma = dot(s, y) / dot(y, y);
+        for qi in &mut q {
+            *qi *= gamma;
+        }
+    }
+
+    for ((s, y, rho), alpha) in history.iter().zip(alphas.iter().rev()) {
+        let beta = rho * dot(y, &q);
+        for (qi, si) in q.iter_mut().zip(s) {
+            *qi += (alpha - beta) * si;
+        }
+    }
+
+    q.iter().map(|v| -v).collect()
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
@@ -0,0 +1,384 @@
+use ndarray::Array1;
+use serde::{Deserialize, Serialize};
+use super::Activation;
+
+/// Fonction de perte évaluée sur la dernière couche du réseau
+///
+/// Les pertes reçoivent les logits (pré-activations) en plus des sorties, ce qui permet
+/// aux entropies croisées d'être calculées sans jamais passer par `ln(0)`.
+pub trait Loss: Send + Sync {
+    /// Perte d'un échantillon (moyenne sur les sorties)
+    fn value(&self, logits: &Array1<f64>, output: &Array1<f64>, target: &Array1<f64>) -> f64;
+
+    /// Gradient par rapport aux logits de la perte sommée sur les sorties (de ½·Σ(o − t)²
+    /// pour `Mse`), point de départ de la rétropropagation; `gradient_scale` le ramène au
+    /// gradient de `value`
+    ///
+    /// Les entropies croisées et la perte focale dérivent directement les logits de la sortie
+    /// attendue (sigmoid ou softmax), vérifiée une fois par `LossKind::check_output`.
+    fn delta(
+        &self,
+        logits: &Array1<f64>,
+        output: &Array1<f64>,
+        target: &Array1<f64>,
+        activation: &Activation,
+    ) -> Array1<f64>;
+
+    /// Facteur tel que `gradient_scale · delta` soit le gradient de `value` par rapport aux
+    /// logits, pour `output_size` sorties (1/K pour une perte moyennée sur les sorties)
+    fn gradient_scale(&self, output_size: usize) -> f64 {
+        1.0 / output_size as f64
+    }
+}
+
+/// Choix de perte sérialisable, instancié au début de chaque entraînement
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum LossKind {
+    #[default]
+    Mse,
+    BinaryCrossEntropy { pos_weight: f64 },
+    CategoricalCrossEntropy,
+    Focal { gamma: f64, alpha: f64 },
+    Huber { delta: f64 },
+    LogCosh,
+    Mae,
+    Quantile { tau: f64 },
+}
+
+impl LossKind {
+    /// Entropie croisée binaire sans pondération
+    pub fn bce() -> Self {
+        Self::BinaryCrossEntropy { pos_weight: 1.0 }
+    }
+
+    /// Vérifie que l'activation de sortie est celle dont la perte dérive les logits
+    pub fn check_output(&self, activation: &Activation) -> Result<(), String> {
+        let (expected, matches) = match self {
+            Self::BinaryCrossEntropy { .. } | Self::Focal { .. } => ("Sigmoid", matches!(activation, Activation::Sigmoid)),
+            Self::CategoricalCrossEntropy => ("Softmax", matches!(activation, Activation::Softmax)),
+            _ => return Ok(()),
+        };
+        if matches {
+            Ok(())
+        } else {
+            Err(format!("la perte {:?} attend une sortie {}, pas {:?}", self, expected, activation))
+        }
+    }
+
+    /// Crée la perte correspondante
+    pub fn build(&self) -> Box<dyn Loss> {
+        match *self {
+            Self::Mse => Box::new(Mse),
+            Self::BinaryCrossEntropy { pos_weight } => Box::new(BinaryCrossEntropy::with_pos_weight(pos_weight)),
+            Self::CategoricalCrossEntropy => Box::new(CategoricalCrossEntropy),
+            Self::Focal { gamma, alpha } => Box::new(FocalLoss::new(gamma, alpha)),
+            Self::Huber { delta } => Box::new(Huber::new(delta)),
+            Self::LogCosh => Box::new(LogCosh),
+            Self::Mae => Box::new(Mae),
+            Self::Quantile { tau } => Box::new(Quantile::new(tau)),
+        }
+    }
+}
+
+/// Erreur quadratique moyenne
+///
+/// Le gradient est celui de ½·Σ(o − t)², convention historique des méthodes `train_*` ;
+/// la valeur rapportée reste la moyenne des carrés.
+pub struct Mse;
+
+impl Loss for Mse {
+    fn value(&self, _logits: &Array1<f64>, output: &Array1<f64>, target: &Array1<f64>) -> f64 {
+        output.iter().zip(target.iter())
+            .map(|(o, t)| (o - t).powi(2))
+            .sum::<f64>() / output.len() as f64
+    }
+
+    fn delta(
+        &self,
+        logits: &Array1<f64>,
+        output: &Array1<f64>,
+        target: &Array1<f64>,
+        activation: &Activation,
+    ) -> Array1<f64> {
+        activation.backward(logits, &(output - target))
+    }
+
+    fn gradient_scale(&self, output_size: usize) -> f64 {
+        2.0 / output_size as f64
+    }
+}
+
+/// Entropie croisée binaire calculée sur les logits d'une sortie sigmoid
+///
+/// `pos_weight` multiplie le terme de la classe positive (Parkinson) pour compenser
+/// le déséquilibre des classes.
+pub struct BinaryCrossEntropy {
+    pub pos_weight: f64,
+}
+
+impl BinaryCrossEntropy {
+    /// Entropie croisée binaire sans pondération
+    pub fn new() -> Self {
+        Self { pos_weight: 1.0 }
+    }
+
+    /// Entropie croisée avec pondération de la classe positive
+    pub fn with_pos_weight(pos_weight: f64) -> Self {
+        Self { pos_weight }
+    }
+}
+
+impl Default for BinaryCrossEntropy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Loss for BinaryCrossEntropy {
+    fn value(&self, logits: &Array1<f64>, _output: &Array1<f64>, target: &Array1<f64>) -> f64 {
+        // -[w·t·ln σ(z) + (1 − t)·ln(1 − σ(z))] avec ln σ(z) = −softplus(−z)
+        logits.iter().zip(target.iter())
+            .map(|(&z, &t)| self.pos_weight * t * softplus(-z) + (1.0 - t) * softplus(z))
+            .sum::<f64>() / logits.len() as f64
+    }
+
+    fn delta(
+        &self,
+        logits: &Array1<f64>,
+        _output: &Array1<f64>,
+        target: &Array1<f64>,
+        _activation: &Activation,
+    ) -> Array1<f64> {
+        logits.iter().zip(target.iter())
+            .map(|(&z, &t)| sigmoid(z) * (self.pos_weight * t + 1.0 - t) - self.pos_weight * t)
+            .collect()
+    }
+}
+
+/// Entropie croisée catégorielle calculée sur les logits d'une sortie softmax
+///
+/// La cible est une distribution sur les classes (one-hot en général). La valeur est
+/// l'entropie croisée de l'échantillon, sommée et non moyennée sur les classes.
+pub struct CategoricalCrossEntropy;
+
+impl Loss for CategoricalCrossEntropy {
+    fn value(&self, logits: &Array1<f64>, _output: &Array1<f64>, target: &Array1<f64>) -> f64 {
+        // −Σ t·ln softmax(z) = Σt·logsumexp(z) − Σ t·z
+        target.sum() * log_sum_exp(logits) - target.dot(logits)
+    }
+
+    fn delta(
+        &self,
+        logits: &Array1<f64>,
+        _output: &Array1<f64>,
+        target: &Array1<f64>,
+        _activation: &Activation,
+    ) -> Array1<f64> {
+        let log_normalizer = log_sum_exp(logits);
+        let softmax = logits.mapv(|z| (z - log_normalizer).exp());
+        softmax * target.sum() - target
+    }
+
+    fn gradient_scale(&self, _output_size: usize) -> f64 {
+        1.0
+    }
+}
+
+/// Perte focale binaire: −α_t·(1 − p_t)^γ·ln(p_t), sur les logits d'une sortie sigmoid
+///
+/// Réduit le poids des exemples déjà bien classés pour concentrer l'apprentissage
+/// sur les cas difficiles.
+pub struct FocalLoss {
+    pub gamma: f64,
+    /// Poids de la classe positive (1 − α pour la classe négative)
+    pub alpha: f64,
+}
+
+impl FocalLoss {
+    /// Crée une perte focale
+    pub fn new(gamma: f64, alpha: f64) -> Self {
+        Self { gamma, alpha }
+    }
+
+    /// Signe de la classe (+1 positive, −1 négative) et poids α_t
+    fn class_terms(&self, target: f64) -> (f64, f64) {
+        if target > 0.5 { (1.0, self.alpha) } else { (-1.0, 1.0 - self.alpha) }
+    }
+}
+
+impl Loss for FocalLoss {
+    fn value(&self, logits: &Array1<f64>, _output: &Array1<f64>, target: &Array1<f64>) -> f64 {
+        logits.iter().zip(target.iter())
+            .map(|(&z, &t)| {
+                let (sign, alpha) = self.class_terms(t);
+                let p_t = sigmoid(sign * z);
+                alpha * (1.0 - p_t).powf(self.gamma) * softplus(-sign * z)
+            })
+            .sum::<f64>() / logits.len() as f64
+    }
+
+    fn delta(
+        &self,
+        logits: &Array1<f64>,
+        _output: &Array1<f64>,
+        target: &Array1<f64>,
+        _activation: &Activation,
+    ) -> Array1<f64> {
+        logits.iter().zip(target.iter())
+            .map(|(&z, &t)| {
+                let (sign, alpha) = self.class_terms(t);
+                let p_t = sigmoid(sign * z);
+                let log_p_t = -softplus(-sign * z);
+                sign * alpha * (1.0 - p_t).powf(self.gamma) * (self.gamma * p_t * log_p_t + p_t - 1.0)
+            })
+            .collect()
+    }
+}
+
+/// Perte de Huber: quadratique jusqu'à `delta`, linéaire au-delà
+///
+/// Les visites aberrantes n'apportent qu'un gradient borné par `delta`.
+pub struct Huber {
+    pub delta: f64,
+}
+
+impl Huber {
+    /// Crée une perte de Huber
+    pub fn new(delta: f64) -> Self {
+        Self { delta }
+    }
+}
+
+impl Loss for Huber {
+    fn value(&self, _logits: &Array1<f64>, output: &Array1<f64>, target: &Array1<f64>) -> f64 {
+        mean_residual(output, target, |r| {
+            if r.abs() <= self.delta { 0.5 * r * r } else { self.delta * (r.abs() - 0.5 * self.delta) }
+        })
+    }
+
+    fn delta(
+        &self,
+        logits: &Array1<f64>,
+        output: &Array1<f64>,
+        target: &Array1<f64>,
+        activation: &Activation,
+    ) -> Array1<f64> {
+        residual_delta(logits, output, target, activation, |r| r.clamp(-self.delta, self.delta))
+    }
+}
+
+/// Log-cosh: proche de ½r² pour les petits résidus, de |r| pour les grands
+pub struct LogCosh;
+
+impl Loss for LogCosh {
+    fn value(&self, _logits: &Array1<f64>, output: &Array1<f64>, target: &Array1<f64>) -> f64 {
+        // ln cosh(r) = |r| + ln(1 + e^(−2|r|)) − ln 2, sans débordement de cosh
+        mean_residual(output, target, |r| {
+            r.abs() + (-2.0 * r.abs()).exp().ln_1p() - std::f64::consts::LN_2
+        })
+    }
+
+    fn delta(
+        &self,
+        logits: &Array1<f64>,
+        output: &Array1<f64>,
+        target: &Array1<f64>,
+        activation: &Activation,
+    ) -> Array1<f64> {
+        residual_delta(logits, output, target, activation, f64::tanh)
+    }
+}
+
+/// Erreur absolue moyenne
+pub struct Mae;
+
+impl Loss for Mae {
+    fn value(&self, _logits: &Array1<f64>, output: &Array1<f64>, target: &Array1<f64>) -> f64 {
+        mean_residual(output, target, f64::abs)
+    }
+
+    fn delta(
+        &self,
+        logits: &Array1<f64>,
+        output: &Array1<f64>,
+        target: &Array1<f64>,
+        activation: &Activation,
+    ) -> Array1<f64> {
+        residual_delta(logits, output, target, activation, |r| {
+            if r > 0.0 { 1.0 } else if r < 0.0 { -1.0 } else { 0.0 }
+        })
+    }
+}
+
+/// Perte quantile (pinball): estime le quantile `tau` de l'UPDRS plutôt que sa moyenne
+pub struct Quantile {
+    pub tau: f64,
+}
+
+impl Quantile {
+    /// Crée une perte quantile (`tau` = 0.5 équivaut à la moitié de la MAE)
+    pub fn new(tau: f64) -> Self {
+        Self { tau }
+    }
+}
+
+impl Loss for Quantile {
+    fn value(&self, _logits: &Array1<f64>, output: &Array1<f64>, target: &Array1<f64>) -> f64 {
+        // Résidu r = o − t: sous-estimation pénalisée par τ, surestimation par 1 − τ
+        mean_residual(output, target, |r| if r < 0.0 { -self.tau * r } else { (1.0 - self.tau) * r })
+    }
+
+    fn delta(
+        &self,
+        logits: &Array1<f64>,
+        output: &Array1<f64>,
+        target: &Array1<f64>,
+        activation: &Activation,
+    ) -> Array1<f64> {
+        residual_delta(logits, output, target, activation, |r| {
+            if r < 0.0 { -self.tau } else if r > 0.0 { 1.0 - self.tau } else { 0.0 }
+        })
+    }
+}
+
+/// Moyenne d'une fonction du résidu o − t
+fn mean_residual(output: &Array1<f64>, target: &Array1<f64>, f: impl Fn(f64) -> f64) -> f64 {
+    output.iter().zip(target.iter())
+        .map(|(o, t)| f(o - t))
+        .sum::<f64>() / output.len() as f64
+}
+
+/// Gradient par rapport aux logits d'une perte définie sur le résidu o − t
+fn residual_delta(
+    logits: &Array1<f64>,
+    output: &Array1<f64>,
+    target: &Array1<f64>,
+    activation: &Activation,
+    gradient: impl Fn(f64) -> f64,
+) -> Array1<f64> {
+    let output_gradient: Array1<f64> = output.iter().zip(target.iter())
+        .map(|(o, t)| gradient(o - t))
+        .collect();
+    activation.backward(logits, &output_gradient)
+}
+
+/// ln Σ eᶻ, décalé par le maximum pour éviter les débordements
+fn log_sum_exp(logits: &Array1<f64>) -> f64 {
+    let max = logits.fold(f64::NEG_INFINITY, |a, &b| a.max(b));
+    max + logits.iter().map(|z| (z - max).exp()).sum::<f64>().ln()
+}
+
+/// ln(1 + eˣ) sans débordement
+fn softplus(x: f64) -> f64 {
+    x.max(0.0) + (-x.abs()).exp().ln_1p()
+}
+
+/// Sigmoid stable pour les grands logits négatifs
+fn sigmoid(x: f64) -> f64 {
+    if x >= 0.0 {
+        1.0 / (1.0 + (-x).exp())
+    } else {
+        let e = x.exp();
+        e / (1.0 + e)
+    }
+}
@@ -0,0 +1,127 @@
+use ndarray::Array1;
+use super::{Mode, NeuralNetwork};
+
+/// Paramètres du test de plage du taux d'apprentissage
+#[derive(Debug, Clone)]
+pub struct LrFinderConfig {
+    pub min_lr: f64,
+    pub max_lr: f64,
+    /// Nombre de batches, le taux croissant exponentiellement de `min_lr` à `max_lr`
+    pub steps: usize,
+    pub batch_size: usize,
+    /// Coefficient de la moyenne mobile appliquée aux pertes
+    pub smoothing: f64,
+    /// Arrêt dès que la perte lissée dépasse ce multiple de la meilleure perte
+    pub divergence_factor: f64,
+}
+
+impl Default for LrFinderConfig {
+    fn default() -> Self {
+        Self {
+            min_lr: 1e-6,
+            max_lr: 10.0,
+            steps: 200,
+            batch_size: 32,
+            smoothing: 0.98,
+            divergence_factor: 4.0,
+        }
+    }
+}
+
+/// Résultat du test: perte lissée pour chaque taux essayé
+#[derive(Debug, Clone)]
+pub struct LrFinderResult {
+    pub learning_rates: Vec<f64>,
+    pub losses: Vec<f64>,
+    /// Taux donnant la perte lissée minimale
+    pub min_loss_lr: f64,
+    /// Taux conseillé: un ordre de grandeur sous le minimum, encore en pente descendante
+    pub suggested_lr: f64,
+}
+
+impl NeuralNetwork {
+    /// TEST DE PLAGE DU LEARNING RATE
+    ///
+    /// Entraîne le réseau quelques centaines de batches en augmentant le taux à chaque pas,
+    /// avec l'optimiseur et la perte configurés. Les poids sont restaurés à la fin.
+    ///
+    /// Échoue sans données ou si les bornes du taux ne sont pas strictement positives.
+    pub fn find_learning_rate(
+        &mut self,
+        inputs: &[Array1<f64>],
+        targets: &[Array1<f64>],
+        config: &LrFinderConfig,
+    ) -> Result<LrFinderResult, Box<dyn std::error::Error>> {
+        if inputs.is_empty() {
+            return Err("recherche du learning rate sans échantillon".into());
+        }
+        if !(config.min_lr > 0.0 && config.max_lr > 0.0) {
+            return Err(format!(
+                "bornes du learning rate invalides: {:e} à {:e} (strictement positives attendues)",
+                config.min_lr, config.max_lr
+            ).into());
+        }
+        self.check_loss_output(&self.loss)?;
+
+        let initial_layers = self.layers.clone();
+        let loss = self.loss.build();
+        let mut optimizer = self.optimizer.build(config.min_lr);
+
+        let steps = config.steps.max(2);
+        let growth = (config.max_lr / config.min_lr).powf(1.0 / (steps - 1) as f64);
+        let batch_size = config.batch_size.clamp(1, inputs.len());
+
+        println!("🔎 Recherche du learning rate - {} pas de {:.0e} à {:.0e}", steps, config.min_lr, config.max_lr);
+
+        let mut learning_rates = Vec::with_capacity(steps);
+        let mut losses = Vec::with_capacity(steps);
+        let mut indices: Vec<usize> = (0..inputs.len()).collect();
+        let mut position = indices.len();
+        let mut average = 0.0;
+        let mut best = f64::INFINITY;
+
+        for step in 0..steps {
+            if position + batch_size > indices.len() {
+                Self::shuffle_indices_fast(&mut indices);
+                position = 0;
+            }
+            let batch = &indices[position..position + batch_size];
+            position += batch_size;
+
+            let learning_rate = config.min_lr * growth.powi(step as i32);
+            optimizer.set_learning_rate(learning_rate);
+
+            let (total_loss, gradients) =
+                self.accumulate_gradients(loss.as_ref(), inputs, targets, batch, Mode::Training);
+            let penalty = self.apply_gradients(optimizer.as_mut(), gradients, batch.len());
+            let batch_loss = total_loss / batch.len() as f64 + penalty;
+
+            // Moyenne mobile corrigée du biais de démarrage
+            average = config.smoothing * average + (1.0 - config.smoothing) * batch_loss;
+            let smoothed = average / (1.0 - config.smoothing.powi(step as i32 + 1));
+
+            learning_rates.push(learning_rate);
+            losses.push(smoothed);
+
+            if !smoothed.is_finite() || smoothed > config.divergence_factor * best {
+                println!("   Divergence à lr = {:.2e} (pas {})", learning_rate, step);
+                break;
+            }
+            best = best.min(smoothed);
+        }
+
+        // Poids et moyennes mobiles des BatchNorm
+        self.layers = initial_layers;
+
+        let min_index = losses.iter().enumerate()
+            .filter(|(_, loss)| loss.is_finite())
+            .min_by(|a, b| a.1.total_cmp(b.1))
+            .map_or(0, |(index, _)| index);
+        let min_loss_lr = learning_rates.get(min_index).copied().unwrap_or(config.min_lr);
+        let suggested_lr = (min_loss_lr / 10.0).max(config.min_lr);
+
+        println!("   Perte minimale à lr = {:.2e} → lr conseillé: {:.2e}", min_loss_lr, suggested_lr);
+
+        Ok(LrFinderResult { learning_rates, losses, min_loss_lr, suggested_lr })
+    }
+}
@@ -1,22 +1,107 @@
 pub mod activation;
+pub mod binary;
+pub mod callbacks;
+pub mod dropout;
+pub mod early_stopping;
+pub mod gradients;
+pub mod initializer;
 pub mod layer;
+pub mod lbfgs;
+pub mod loss;
+pub mod lr_finder;
+pub mod normalization;
 pub mod optimizers;
+pub mod parallel;
+pub mod persistence;
+pub mod regularization;
+pub mod sam;
+pub mod scheduler;
+pub mod trainer;
 
 pub use activation::Activation;
+pub use binary::Precision;
+pub use callbacks::{BatchSummary, Callback, Checkpoint, EpochSummary};
+pub use dropout::{Dropout, DropoutKind};
+pub use early_stopping::{EarlyStopping, ValidationMetric};
+pub use gradients::{BatchStatistics, Gradients, LayerGradient};
+pub use initializer::Initializer;
 pub use layer::Layer;
-pub use optimizers::SGD;
-
-use ndarray::{Array1, Array2};
+pub use lbfgs::LbfgsConfig;
+pub use loss::{BinaryCrossEntropy, CategoricalCrossEntropy, FocalLoss, Huber, LogCosh, Loss, LossKind, Mae, Mse, Quantile};
+pub use lr_finder::{LrFinderConfig, LrFinderResult};
+pub use normalization::{Normalization, NormalizationKind};
+pub use optimizers::{Adagrad, Adam, Momentum, Optimizer, OptimizerKind, RmsProp, SGD};
+pub use persistence::MODEL_FORMAT_VERSION;
+pub use regularization::Regularization;
+pub use sam::SamConfig;
+pub use scheduler::{
+    ConstantLr, CosineWarmRestarts, ExponentialLr, LinearWarmup, LrScheduler, OneCycle, ReduceOnPlateau,
+    SchedulerKind, StepLr,
+};
+pub use trainer::{SampleWeights, Trainer, TrainingConfig};
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use ndarray::{Array1, Array2, Axis};
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use serde::{Deserialize, Serialize};
+use crate::data::preprocessing::MinMaxScaler;
+use normalization::NormalizationCache;
+use parallel::NormalizationPart;
+
+/// Mode de fonctionnement du réseau
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Mode {
+    /// Dropout actif (utilisé pour calculer les gradients)
+    Training,
+    /// Prédiction déterministe
+    #[default]
+    Inference,
+}
 
-/// Métriques de suivi pendant l'entraînement
+/// Valeurs mémorisées pour une couche par la propagation avant
 #[derive(Debug, Clone)]
+pub struct CachedLayer {
+    pub input: Array1<f64>,
+    /// Pré-activation (logits pour la couche de sortie)
+    pub z: Array1<f64>,
+    /// Normalisation appliquée avant l'activation
+    pub normalization: Option<NormalizationCache>,
+    /// Dérivée du dropout appliqué en sortie de couche, s'il était actif
+    pub dropout_mask: Option<Array1<f64>>,
+}
+
+/// Cache d'une couche pour un batch (une ligne par échantillon)
+struct BatchCache {
+    input: Array2<f64>,
+    z: Array2<f64>,
+    normalization: Option<NormalizationCache>,
+    dropout_mask: Option<Array2<f64>>,
+}
+
+/// Cache de chaque couche pour la rétropropagation
+pub type LayerCache = Vec<CachedLayer>;
+
+/// Jeu de validation (entrées, cibles) suivi pendant l'entraînement
+pub type ValidationSet<'a> = (&'a [Array1<f64>], &'a [Array1<f64>]);
+
+/// Métriques de suivi pendant l'entraînement
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrainingMetrics {
+    #[serde(with = "persistence::non_finite_vec")]
     pub losses: Vec<f64>,
+    #[serde(with = "persistence::non_finite_vec")]
     pub gradients_norm: Vec<f64>,
+    #[serde(with = "persistence::non_finite_vec")]
     pub learning_rates: Vec<f64>,
+    #[serde(with = "persistence::non_finite")]
     pub best_loss: f64,
     pub patience_counter: usize,
+    /// Perte sur le jeu de validation à chaque epoch, quand il est fourni
+    #[serde(default, with = "persistence::non_finite_vec")]
+    pub validation_losses: Vec<f64>,
 }
 
 impl TrainingMetrics {
@@ -28,6 +113,7 @@ impl TrainingMetrics {
             learning_rates: Vec::new(),
             best_loss: f64::INFINITY,
             patience_counter: 0,
+            validation_losses: Vec::new(),
         }
     }
 
@@ -49,11 +135,26 @@ impl TrainingMetrics {
     }
 }
 
+impl Default for TrainingMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Réseau neuronal optimisé
 pub struct NeuralNetwork {
     pub layers: Vec<Layer>,
     learning_rate: f64,
     metrics: TrainingMetrics,
+    feature_names: Vec<String>,
+    scaler: Option<MinMaxScaler>,
+    optimizer: OptimizerKind,
+    loss: LossKind,
+    scheduler: Option<SchedulerKind>,
+    mode: Mode,
+    seed: u64,
+    dropout_draws: AtomicU64,
+    threads: usize,
 }
 
 impl NeuralNetwork {
@@ -63,6 +164,15 @@ impl NeuralNetwork {
             layers: Vec::new(),
             learning_rate,
             metrics: TrainingMetrics::new(),
+            feature_names: Vec::new(),
+            scaler: None,
+            optimizer: OptimizerKind::default(),
+            loss: LossKind::default(),
+            scheduler: None,
+            mode: Mode::default(),
+            seed: rand::random(),
+            dropout_draws: AtomicU64::new(0),
+            threads: 1,
         }
     }
 
@@ -73,8 +183,166 @@ impl NeuralNetwork {
         self
     }
 
+    /// Ajoute une couche initialisée avec le schéma choisi
+    pub fn add_layer_with_initializer(
+        &mut self,
+        input_size: usize,
+        output_size: usize,
+        activation: Activation,
+        initializer: Initializer,
+    ) -> &mut Self {
+        let layer = Layer::with_initializer(input_size, output_size, activation, initializer);
+        self.layers.push(layer);
+        self
+    }
+
+    /// Ajoute un dropout en sortie de la dernière couche ajoutée (ignoré sur la couche de sortie)
+    pub fn add_dropout(&mut self, dropout: Dropout) -> &mut Self {
+        if let Some(layer) = self.layers.last_mut() {
+            layer.dropout = Some(dropout);
+        }
+        self
+    }
+
+    /// Normalise les pré-activations de la dernière couche ajoutée
+    pub fn add_normalization(&mut self, kind: NormalizationKind) -> &mut Self {
+        if let Some(layer) = self.layers.last_mut() {
+            layer.normalization = Some(Normalization::new(kind, layer.output_size));
+        }
+        self
+    }
+
+    /// Passe en mode entraînement ou inférence pour `forward` et `forward_with_cache`
+    ///
+    /// Les méthodes `train_*` calculent toujours leurs gradients en mode entraînement.
+    pub fn set_mode(&mut self, mode: Mode) -> &mut Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Mode courant
+    pub fn mode(&self) -> Mode {
+        self.mode
+    }
+
+    /// Fixe la graine des masques de dropout (séquence reproductible)
+    pub fn set_seed(&mut self, seed: u64) -> &mut Self {
+        self.seed = seed;
+        self.dropout_draws.store(0, Ordering::Relaxed);
+        self
+    }
+
+    /// Nombre de threads calculant les gradients de chaque batch (1 par défaut)
+    pub fn set_threads(&mut self, threads: usize) -> &mut Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Nombre de threads d'entraînement
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// Modifie le taux d'apprentissage de base (ex. celui conseillé par `find_learning_rate`)
+    pub fn set_learning_rate(&mut self, learning_rate: f64) -> &mut Self {
+        self.learning_rate = learning_rate;
+        self
+    }
+
+    /// Taux d'apprentissage de base
+    pub fn learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+
+    /// Régularise la dernière couche ajoutée
+    pub fn add_regularization(&mut self, regularization: Regularization) -> &mut Self {
+        if let Some(layer) = self.layers.last_mut() {
+            layer.regularization = Some(regularization);
+        }
+        self
+    }
+
+    /// Choisit l'optimiseur utilisé par toutes les méthodes `train_*`
+    pub fn set_optimizer(&mut self, optimizer: OptimizerKind) -> &mut Self {
+        self.optimizer = optimizer;
+        self
+    }
+
+    /// Choisit la perte utilisée par `train_sam` et par `Trainer` quand `TrainingConfig::loss`
+    /// n'en impose pas une autre
+    pub fn set_loss(&mut self, loss: LossKind) -> &mut Self {
+        self.loss = loss;
+        self
+    }
+
+    /// Perte choisie par `set_loss`
+    pub fn loss(&self) -> &LossKind {
+        &self.loss
+    }
+
+    /// Remplace la planification du taux d'apprentissage propre à chaque méthode `train_*`
+    pub fn set_scheduler(&mut self, scheduler: SchedulerKind) -> &mut Self {
+        self.scheduler = Some(scheduler);
+        self
+    }
+
+    /// Nomme les features d'entrée (conservés avec le modèle)
+    pub fn set_feature_names<S: AsRef<str>>(&mut self, names: &[S]) -> &mut Self {
+        self.feature_names = names.iter().map(|name| name.as_ref().to_string()).collect();
+        self
+    }
+
+    /// Associe le scaler utilisé pour normaliser les entrées d'entraînement
+    pub fn set_scaler(&mut self, scaler: MinMaxScaler) -> &mut Self {
+        self.scaler = Some(scaler);
+        self
+    }
+
+    /// Noms des features d'entrée
+    pub fn feature_names(&self) -> &[String] {
+        &self.feature_names
+    }
+
+    /// Scaler des entrées, s'il a été fourni
+    pub fn scaler(&self) -> Option<&MinMaxScaler> {
+        self.scaler.as_ref()
+    }
+
+    /// Métriques du dernier entraînement
+    pub fn metrics(&self) -> &TrainingMetrics {
+        &self.metrics
+    }
+
+    /// Nombre total de paramètres entraînables
+    pub fn parameter_count(&self) -> usize {
+        self.layers.iter()
+            .map(|layer| layer.parameters().count())
+            .sum()
+    }
+
+    /// Paramètres aplatis (poids, biais puis normalisation de chaque couche)
+    pub fn parameters(&self) -> Vec<f64> {
+        self.layers.iter().flat_map(Layer::parameters).copied().collect()
+    }
+
+    /// Remplace les paramètres à partir d'un vecteur aplati par `parameters`
+    pub fn set_parameters(&mut self, parameters: &[f64]) {
+        for (value, &parameter) in self.layers.iter_mut().flat_map(Layer::parameters_mut).zip(parameters) {
+            *value = parameter;
+        }
+    }
+
+    /// Taille d'entrée attendue par la première couche
+    pub fn input_size(&self) -> Option<usize> {
+        self.layers.first().map(|layer| layer.input_size)
+    }
+
     /// Propagation avant à travers tout le réseau
     pub fn forward(&self, input: &Array1<f64>) -> Array1<f64> {
+        if self.mode == Mode::Training {
+            return self.forward_cached(input, Mode::Training).0;
+        }
+
         let mut output = input.clone();
         for layer in &self.layers {
             output = layer.forward(&output);
@@ -82,6 +350,14 @@ impl NeuralNetwork {
         output
     }
 
+    /// Indice de la sortie la plus élevée (classe prédite d'une sortie softmax)
+    pub fn predict_class(&self, input: &Array1<f64>) -> usize {
+        self.forward(input).iter()
+            .enumerate()
+            .fold((0, f64::NEG_INFINITY), |best, (i, &value)| if value > best.1 { (i, value) } else { best })
+            .0
+    }
+
     /// ENTRAÎNEMENT ULTRA RAPIDE
     pub fn train_fast(
         &mut self, 
@@ -90,54 +366,7 @@ impl NeuralNetwork {
         epochs: usize,
         batch_size: usize,
     ) -> TrainingMetrics {
-        let mut optimizer = SGD::new(self.learning_rate);
-        
-        println!("⚡ Entraînement rapide - {} samples, batch: {}", inputs.len(), batch_size);
-        
-        for epoch in 0..epochs {
-            let mut epoch_loss = 0.0;
-            let mut batches_processed = 0;
-            
-            // Mélange optimisé
-            let mut indices: Vec<usize> = (0..inputs.len()).collect();
-            Self::shuffle_indices_fast(&mut indices);
-            
-            for batch_start in (0..inputs.len()).step_by(batch_size) {
-                let batch_end = (batch_start + batch_size).min(inputs.len());
-                let batch_loss = self.process_batch_ultra_fast(
-                    inputs, 
-                    targets, 
-                    &indices[batch_start..batch_end],
-                    &mut optimizer
-                );
-                epoch_loss += batch_loss;
-                batches_processed += 1;
-            }
-            
-            if batches_processed > 0 {
-                let avg_loss = epoch_loss / batches_processed as f64;
-                
-                // Learning rate adaptatif agressif
-                optimizer.learning_rate = self.aggressive_learning_rate(epoch, avg_loss, optimizer.learning_rate);
-                
-                let improved = self.metrics.update(avg_loss, 0.0, optimizer.learning_rate);
-                
-                // Affichage minimal pour performance
-                if epoch % 20 == 0 || epoch == epochs - 1 || improved {
-                    let marker = if improved { "🚀" } else { "  " };
-                    println!("Epoch {:3} {} Loss: {:.6}", epoch, marker, avg_loss);
-                }
-                
-                // Early stopping agressif
-                if self.metrics.patience_counter > 30 {
-                    println!("⏹️  Arrêt early à epoch {}", epoch);
-                    break;
-                }
-            }
-        }
-        
-        println!("✅ Entraînement terminé! Best loss: {:.6}", self.metrics.best_loss);
-        self.metrics.clone()
+        Trainer::new(TrainingConfig { epochs, batch_size, ..TrainingConfig::fast() }).fit(self, inputs, targets, None)
     }
 
     /// ENTRAÎNEMENT AVEC GESTION DU DÉSÉQUILIBRE
@@ -148,57 +377,13 @@ impl NeuralNetwork {
         epochs: usize,
         batch_size: usize,
     ) -> TrainingMetrics {
-        let mut optimizer = SGD::new(self.learning_rate);
-        
-        println!("🎯 Entraînement équilibré - {} samples", inputs.len());
-        println!("   Batch size: {}, Epochs: {}", batch_size, epochs);
-        
-        for epoch in 0..epochs {
-            let mut epoch_loss = 0.0;
-            let mut batches_processed = 0;
-            
-            let mut indices: Vec<usize> = (0..inputs.len()).collect();
-            Self::shuffle_indices_fast(&mut indices);
-            
-            for batch_start in (0..inputs.len()).step_by(batch_size) {
-                let batch_end = (batch_start + batch_size).min(inputs.len());
-                let batch_loss = self.process_batch_balanced(
-                    inputs, 
-                    targets, 
-                    &indices[batch_start..batch_end],
-                    &mut optimizer
-                );
-                epoch_loss += batch_loss;
-                batches_processed += 1;
-            }
-            
-            if batches_processed > 0 {
-                let avg_loss = epoch_loss / batches_processed as f64;
-                
-                // Learning rate adaptatif plus conservateur
-                optimizer.learning_rate = self.conservative_learning_rate(epoch, avg_loss, optimizer.learning_rate);
-                
-                let improved = self.metrics.update(avg_loss, 0.0, optimizer.learning_rate);
-                
-                if epoch % 20 == 0 || epoch == epochs - 1 || improved {
-                    let marker = if improved { "📈" } else { "  " };
-                    println!("Epoch {:3} {} Loss: {:.6} | LR: {:.6}", 
-                        epoch, marker, avg_loss, optimizer.learning_rate);
-                }
-                
-                // Early stopping plus patient
-                if self.metrics.patience_counter > 50 {
-                    println!("⏹️  Arrêt équilibré à epoch {}", epoch);
-                    break;
-                }
-            }
-        }
-        
-        println!("✅ Entraînement équilibré terminé! Best loss: {:.6}", self.metrics.best_loss);
-        self.metrics.clone()
+        Trainer::new(TrainingConfig { epochs, batch_size, ..TrainingConfig::balanced() }).fit(self, inputs, targets, None)
     }
 
     /// ENTRAÎNEMENT AVEC POIDS DE CLASSE POUR DÉSÉQUILIBRE
+    ///
+    /// Minimise l'entropie croisée binaire sur les logits de la sortie sigmoid,
+    /// chaque échantillon étant pondéré par le poids de sa classe.
     pub fn train_with_class_weights(
         &mut self, 
         inputs: &[Array1<f64>], 
@@ -206,369 +391,215 @@ impl NeuralNetwork {
         epochs: usize,
         batch_size: usize,
     ) -> TrainingMetrics {
-        let mut optimizer = SGD::new(self.learning_rate);
-        
-        // Calculer les poids de classe
-        let (class_weight_positive, class_weight_negative) = self.calculate_class_weights(targets);
-        
-        println!("🎯 Entraînement avec poids de classe");
-        println!("   Poids: Parkinson={:.2}, Sain={:.2}", class_weight_positive, class_weight_negative);
-        println!("   Samples: {}, Batch: {}", inputs.len(), batch_size);
-        
-        for epoch in 0..epochs {
-            let mut epoch_loss = 0.0;
-            let mut batches_processed = 0;
-            
-            let mut indices: Vec<usize> = (0..inputs.len()).collect();
-            Self::shuffle_indices_fast(&mut indices);
-            
-            for batch_start in (0..inputs.len()).step_by(batch_size) {
-                let batch_end = (batch_start + batch_size).min(inputs.len());
-                let batch_loss = self.process_batch_weighted(
-                    inputs, 
-                    targets, 
-                    &indices[batch_start..batch_end],
-                    &mut optimizer,
-                    class_weight_positive,
-                    class_weight_negative
-                );
-                epoch_loss += batch_loss;
-                batches_processed += 1;
-            }
-            
-            if batches_processed > 0 {
-                let avg_loss = epoch_loss / batches_processed as f64;
-                
-                let improved = self.metrics.update(avg_loss, 0.0, optimizer.learning_rate);
-                
-                if epoch % 30 == 0 || epoch == epochs - 1 || improved {
-                    let marker = if improved { "📈" } else { "  " };
-                    println!("Epoch {:3} {} Loss: {:.6}", epoch, marker, avg_loss);
-                }
-                
-                if self.metrics.patience_counter > 80 {
-                    println!("⏹️  Arrêt à epoch {}", epoch);
-                    break;
-                }
-            }
-        }
-        
-        println!("✅ Entraînement pondéré terminé! Best loss: {:.6}", self.metrics.best_loss);
-        self.metrics.clone()
+        Trainer::new(TrainingConfig { epochs, batch_size, ..TrainingConfig::class_weighted() }).fit(self, inputs, targets, None)
     }
 
-    /// Traitement de batch ultra rapide
-    fn process_batch_ultra_fast(
+    /// Vérifie, avant l'entraînement, que la couche de sortie convient à la perte
+    pub(crate) fn check_loss_output(&self, loss: &LossKind) -> Result<(), String> {
+        self.layers.last().map_or(Ok(()), |layer| loss.check_output(&layer.activation))
+    }
+
+    /// Planification choisie par `set_scheduler`, sinon celle de la méthode d'entraînement
+    fn build_scheduler(&self, preset: SchedulerKind) -> Box<dyn LrScheduler> {
+        self.scheduler.clone().unwrap_or(preset).build(self.learning_rate)
+    }
+
+    /// Moyenne les gradients accumulés sur le batch et applique un pas d'optimisation
+    ///
+    /// Les pénalités de régularisation sont ajoutées aux gradients moyens, les contraintes
+    /// max-norm imposées après le pas et les moyennes mobiles des BatchNorm mises à jour.
+    /// Retourne la pénalité avant le pas.
+    fn apply_gradients(
         &mut self,
-        inputs: &[Array1<f64>],
-        targets: &[Array1<f64>],
-        batch_indices: &[usize],
-        optimizer: &mut SGD,
+        optimizer: &mut dyn Optimizer,
+        mut gradients: Gradients,
+        batch_size: usize,
     ) -> f64 {
-        let batch_size = batch_indices.len();
-        let mut total_loss = 0.0;
-        
-        // Pré-allocation des gradients
-        let mut weight_gradients: Vec<Array2<f64>> = self.layers.iter()
-            .map(|layer| Array2::zeros((layer.output_size, layer.input_size)))
-            .collect();
-            
-        let mut bias_gradients: Vec<Array1<f64>> = self.layers.iter()
-            .map(|layer| Array1::zeros(layer.output_size))
-            .collect();
+        gradients.scale(1.0 / batch_size as f64);
 
-        // Traitement vectorisé
-        for &idx in batch_indices {
-            let input = &inputs[idx];
-            let target = &targets[idx];
-            
-            let (output, activations) = self.forward_with_cache(input);
-            total_loss += self.mse_loss(&output, target);
-            
-            let gradients = self.backward_fast(&output, target, &activations);
-            
-            for (i, (wg, bg)) in gradients.iter().enumerate() {
-                weight_gradients[i] = &weight_gradients[i] + wg;
-                bias_gradients[i] = &bias_gradients[i] + bg;
-            }
-        }
+        let penalty = self.regularization_penalty();
+        self.add_regularization_gradients(&mut gradients);
 
-        // Mise à jour des poids
-        for (i, layer) in self.layers.iter_mut().enumerate() {
-            let avg_weight_grad = &weight_gradients[i] / batch_size as f64;
-            let avg_bias_grad = &bias_gradients[i] / batch_size as f64;
-            
-            layer.weights = optimizer.update_weights(&layer.weights, &avg_weight_grad);
-            layer.biases = optimizer.update_biases(&layer.biases, &avg_bias_grad);
+        optimizer.step(&mut self.layers, &gradients);
+
+        for (layer, statistics) in self.layers.iter_mut().zip(&gradients.batch_statistics) {
+            if let Some(regularization) = &layer.regularization {
+                regularization.constrain(&mut layer.weights);
+            }
+            if let (Some(normalization), Some((mean, var))) = (&mut layer.normalization, statistics) {
+                normalization.update_running_statistics(mean, var);
+            }
         }
 
-        total_loss / batch_size as f64
+        penalty
     }
 
-    /// Traitement de batch équilibré
-    fn process_batch_balanced(
-        &mut self,
-        inputs: &[Array1<f64>],
-        targets: &[Array1<f64>],
-        batch_indices: &[usize],
-        optimizer: &mut SGD,
-    ) -> f64 {
-        let batch_size = batch_indices.len();
-        let mut total_loss = 0.0;
-        
-        let mut weight_gradients: Vec<Array2<f64>> = self.layers.iter()
-            .map(|layer| Array2::zeros((layer.output_size, layer.input_size)))
-            .collect();
-            
-        let mut bias_gradients: Vec<Array1<f64>> = self.layers.iter()
-            .map(|layer| Array1::zeros(layer.output_size))
-            .collect();
+    /// Somme des pénalités de régularisation de toutes les couches
+    pub fn regularization_penalty(&self) -> f64 {
+        self.layers.iter()
+            .filter_map(|layer| {
+                layer.regularization.as_ref().map(|reg| reg.penalty(&layer.weights, &layer.biases))
+            })
+            .sum()
+    }
 
-        for &idx in batch_indices {
-            let input = &inputs[idx];
-            let target = &targets[idx];
-            
-            let (output, activations) = self.forward_with_cache(input);
-            
-            // Perte avec régularisation implicite pour équilibrage
-            let loss = self.balanced_loss(&output, target);
-            total_loss += loss;
-            
-            let gradients = self.backward_balanced(&output, target, &activations);
-            
-            for (i, (wg, bg)) in gradients.iter().enumerate() {
-                weight_gradients[i] = &weight_gradients[i] + wg;
-                bias_gradients[i] = &bias_gradients[i] + bg;
+    /// Ajoute le gradient des pénalités aux gradients de chaque couche
+    fn add_regularization_gradients(&self, gradients: &mut Gradients) {
+        for (layer, grads) in self.layers.iter().zip(&mut gradients.layers) {
+            if let Some(regularization) = &layer.regularization {
+                regularization.add_gradients(&layer.weights, &layer.biases, &mut grads.weights, &mut grads.biases);
             }
         }
+    }
 
-        // Gradient clipping pour stabilité
-        self.optimal_gradient_clipping(&mut weight_gradients, &mut bias_gradients, 2.0);
+    /// Gradients sommés (non moyennés) de la perte sur les échantillons indiqués
+    ///
+    /// Retourne la somme des pertes et les gradients.
+    fn accumulate_gradients(
+        &self,
+        loss: &dyn Loss,
+        inputs: &[Array1<f64>],
+        targets: &[Array1<f64>],
+        indices: &[usize],
+        mode: Mode,
+    ) -> (f64, Gradients) {
+        self.compute_gradients(inputs, targets, indices, mode, |_, logits, output, target, activation| {
+            (loss.value(logits, output, target), loss.delta(logits, output, target, activation))
+        })
+    }
 
-        for (i, layer) in self.layers.iter_mut().enumerate() {
-            let avg_weight_grad = &weight_gradients[i] / batch_size as f64;
-            let avg_bias_grad = &bias_gradients[i] / batch_size as f64;
-            
-            layer.weights = optimizer.update_weights(&layer.weights, &avg_weight_grad);
-            layer.biases = optimizer.update_biases(&layer.biases, &avg_bias_grad);
+    /// Somme des pertes et des gradients pour un objectif défini par échantillon
+    ///
+    /// `objective` reçoit l'indice de l'échantillon, ses logits, sa sortie, sa cible et
+    /// l'activation à dériver (voir `loss_activation`), et retourne la perte et son
+    /// gradient par rapport aux logits à travers cette activation. Les échantillons sont
+    /// empilés en une matrice propagée d'un bloc (un produit matriciel par couche et par
+    /// sens); en entraînement, les BatchNorm utilisent les statistiques du batch. Avec
+    /// plusieurs threads, le batch est réparti entre eux (voir `parallel_gradients`).
+    fn compute_gradients<F>(
+        &self,
+        inputs: &[Array1<f64>],
+        targets: &[Array1<f64>],
+        indices: &[usize],
+        mode: Mode,
+        objective: F,
+    ) -> (f64, Gradients)
+    where
+        F: Fn(usize, &Array1<f64>, &Array1<f64>, &Array1<f64>, &Activation) -> (f64, Array1<f64>) + Sync,
+    {
+        if self.threads > 1 && indices.len() > 1 {
+            return self.parallel_gradients(inputs, targets, indices, mode, &objective);
         }
+        self.batch_gradients(inputs, targets, indices, mode, &objective, &mut self.dropout_rng(), None)
+    }
 
-        total_loss / batch_size as f64
+    /// Logits et sorties d'échantillons propagés d'un bloc en inférence
+    fn inference_batch(&self, inputs: &[Array1<f64>]) -> (Array2<f64>, Array2<f64>) {
+        let input_size = self.input_size().unwrap_or(0);
+        let batch = Array2::from_shape_fn((inputs.len(), input_size), |(row, col)| inputs[row][col]);
+        let (outputs, mut caches, _) = self.forward_batch(&batch, Mode::Inference, &mut self.dropout_rng(), None);
+        let logits = caches.pop().map_or_else(|| outputs.clone(), |cache| cache.z);
+        (logits, outputs)
     }
 
-    /// Traitement de batch avec poids de classe
-    fn process_batch_weighted(
-        &mut self,
+    /// Pertes et gradients d'une partie du batch, propagée d'un bloc
+    ///
+    /// Avec `shared`, la partie appartient à un batch réparti entre threads dont les
+    /// BatchNorm partagent les statistiques.
+    #[allow(clippy::too_many_arguments)]
+    fn batch_gradients<F>(
+        &self,
         inputs: &[Array1<f64>],
         targets: &[Array1<f64>],
-        batch_indices: &[usize],
-        optimizer: &mut SGD,
-        weight_positive: f64,
-        weight_negative: f64,
-    ) -> f64 {
-        let batch_size = batch_indices.len();
+        indices: &[usize],
+        mode: Mode,
+        objective: &F,
+        rng: &mut StdRng,
+        shared: Option<NormalizationPart>,
+    ) -> (f64, Gradients)
+    where
+        F: Fn(usize, &Array1<f64>, &Array1<f64>, &Array1<f64>, &Activation) -> (f64, Array1<f64>),
+    {
+        let input_size = self.input_size().unwrap_or(0);
+        let batch = Array2::from_shape_fn((indices.len(), input_size), |(row, col)| inputs[indices[row]][col]);
+        let (outputs, caches, batch_statistics) = self.forward_batch(&batch, mode, rng, shared);
+        let logits = &caches[caches.len() - 1].z;
+
+        let activation = self.loss_activation();
         let mut total_loss = 0.0;
-        
-        let mut weight_gradients: Vec<Array2<f64>> = self.layers.iter()
-            .map(|layer| Array2::zeros((layer.output_size, layer.input_size)))
-            .collect();
-            
-        let mut bias_gradients: Vec<Array1<f64>> = self.layers.iter()
-            .map(|layer| Array1::zeros(layer.output_size))
-            .collect();
-
-        for &idx in batch_indices {
-            let input = &inputs[idx];
-            let target = &targets[idx];
-            
-            let (output, activations) = self.forward_with_cache(input);
-            
-            // Perte pondérée selon la classe
-            let class_weight = if target[0] > 0.5 { weight_positive } else { weight_negative };
-            let loss = self.mse_loss(&output, target) * class_weight;
-            total_loss += loss;
-            
-            let mut gradients = self.backward_fast(&output, target, &activations);
-            
-            // Appliquer le poids aux gradients
-            for (wg, bg) in &mut gradients {
-                *wg = wg.mapv(|x| x * class_weight);
-                *bg = bg.mapv(|x| x * class_weight);
-            }
-            
-            for (i, (wg, bg)) in gradients.iter().enumerate() {
-                weight_gradients[i] = &weight_gradients[i] + wg;
-                bias_gradients[i] = &bias_gradients[i] + bg;
-            }
-        }
-
-        for (i, layer) in self.layers.iter_mut().enumerate() {
-            let avg_weight_grad = &weight_gradients[i] / batch_size as f64;
-            let avg_bias_grad = &bias_gradients[i] / batch_size as f64;
-            
-            layer.weights = optimizer.update_weights(&layer.weights, &avg_weight_grad);
-            layer.biases = optimizer.update_biases(&layer.biases, &avg_bias_grad);
+        let mut delta = Array2::zeros(outputs.dim());
+        for (row, &idx) in indices.iter().enumerate() {
+            let (sample_loss, sample_delta) =
+                objective(idx, &logits.row(row).to_owned(), &outputs.row(row).to_owned(), &targets[idx], activation);
+            total_loss += sample_loss;
+            delta.row_mut(row).assign(&sample_delta);
         }
 
-        total_loss / batch_size as f64
+        let mut gradients = self.backpropagate_batch(delta, &caches, shared);
+        gradients.batch_statistics = batch_statistics;
+        (total_loss, gradients)
     }
 
-    /// Perte équilibrée pour gérer le déséquilibre des classes
-    fn balanced_loss(&self, output: &Array1<f64>, target: &Array1<f64>) -> f64 {
-        // MSE avec pondération pour équilibrer les classes
-        let base_loss = self.mse_loss(output, target);
-        
-        // Pénalité supplémentaire pour les faux négatifs (cas importants manqués)
-        if target[0] > 0.5 && output[0] < 0.3 {
-            base_loss * 2.0  // Double pénalité pour faux négatifs
-        } else {
-            base_loss
-        }
-    }
-
-    /// Calcule les poids de classe pour le déséquilibre
-    fn calculate_class_weights(&self, targets: &[Array1<f64>]) -> (f64, f64) {
-        let mut positive_count = 0;
-        let mut negative_count = 0;
-        
-        for target in targets {
-            if target[0] > 0.5 {
-                positive_count += 1;
-            } else {
-                negative_count += 1;
-            }
-        }
-        
-        let total = positive_count + negative_count;
-        let weight_positive = if positive_count > 0 { 
-            total as f64 / (2.0 * positive_count as f64) 
-        } else { 
-            1.0 
-        };
-        let weight_negative = if negative_count > 0 { 
-            total as f64 / (2.0 * negative_count as f64) 
-        } else { 
-            1.0 
-        };
-        
-        (weight_positive, weight_negative)
+    /// Activation de sortie transmise à la perte
+    ///
+    /// Une sortie à paramètres (PReLU) reçoit le gradient par rapport à sa sortie, dont
+    /// dépend celui de ses pentes; `backpropagate_batch` le rétropropage ensuite.
+    fn loss_activation(&self) -> &Activation {
+        let activation = &self.layers[self.layers.len() - 1].activation;
+        if activation.parameters().is_empty() { activation } else { &Activation::Linear }
     }
 
-    /// Rétropropagation optimisée
-    fn backward_fast(
+    /// Rétropropagation d'un batch à partir du gradient rendu par l'objectif (une ligne par
+    /// échantillon): par rapport aux logits, ou à la sortie pour une sortie PReLU
+    fn backpropagate_batch(
         &self,
-        output: &Array1<f64>,
-        target: &Array1<f64>,
-        activations: &[(Array1<f64>, Array1<f64>)],
-    ) -> Vec<(Array2<f64>, Array1<f64>)> {
-        let mut gradients = Vec::new();
-        let mut delta = output - target;
+        mut delta: Array2<f64>,
+        caches: &[BatchCache],
+        shared: Option<NormalizationPart>,
+    ) -> Gradients {
+        let mut gradients = Gradients::zeros(&self.layers);
+        let last = self.layers.len() - 1;
 
         for (i, layer) in self.layers.iter().enumerate().rev() {
-            let (ref input, ref z) = activations[i];
-            
-            let activation_derivative = layer.activation.derivative(z);
-            delta = &delta * &activation_derivative;
-            
-            let weight_gradient = {
-                let delta_2d = delta.view().insert_axis(ndarray::Axis(1));
-                let input_2d = input.view().insert_axis(ndarray::Axis(0));
-                delta_2d.dot(&input_2d)
-            };
-                
-            gradients.push((weight_gradient, delta.clone()));
-            
-            if i > 0 {
-                delta = layer.weights.t().dot(&delta);
+            let cache = &caches[i];
+            let grads = &mut gradients.layers[i];
+
+            // `delta` est ici le gradient par rapport à la sortie de l'activation
+            if i < last || !layer.activation.parameters().is_empty() {
+                grads.slopes = layer.activation.parameter_gradient_rows(&cache.z, &delta);
+                delta = layer.activation.backward_rows(&cache.z, &delta);
             }
-        }
-        
-        gradients.reverse();
-        gradients
-    }
 
-    /// Rétropropagation équilibrée
-    fn backward_balanced(
-        &self,
-        output: &Array1<f64>,
-        target: &Array1<f64>,
-        activations: &[(Array1<f64>, Array1<f64>)],
-    ) -> Vec<(Array2<f64>, Array1<f64>)> {
-        let mut gradients = Vec::new();
-        let mut delta = output - target;
-
-        // Renforcement des gradients pour les cas positifs (Parkinson)
-        if target[0] > 0.5 {
-            delta = &delta * 1.5; // Augmente l'importance des cas Parkinson
-        }
+            if let (Some(normalization), Some(norm_cache)) = (&layer.normalization, &cache.normalization) {
+                let (input_delta, gamma_gradient, beta_gradient) = match shared {
+                    Some(part) => part.backward(i, normalization, &delta, norm_cache),
+                    None => normalization.backward(&delta, norm_cache),
+                };
+                delta = input_delta;
+                grads.gamma = gamma_gradient;
+                grads.beta = beta_gradient;
+            }
+
+            grads.weights = delta.t().dot(&cache.input);
+            grads.biases = delta.sum_axis(Axis(0));
 
-        for (i, layer) in self.layers.iter().enumerate().rev() {
-            let (ref input, ref z) = activations[i];
-            
-            let activation_derivative = layer.activation.derivative(z);
-            delta = &delta * &activation_derivative;
-            
-            let weight_gradient = {
-                let delta_2d = delta.view().insert_axis(ndarray::Axis(1));
-                let input_2d = input.view().insert_axis(ndarray::Axis(0));
-                delta_2d.dot(&input_2d)
-            };
-                
-            gradients.push((weight_gradient, delta.clone()));
-            
             if i > 0 {
-                delta = layer.weights.t().dot(&delta);
+                delta = delta.dot(&layer.weights);
+                if let Some(mask) = &caches[i - 1].dropout_mask {
+                    delta *= mask;
+                }
             }
         }
-        
-        gradients.reverse();
-        gradients
-    }
 
-    /// Learning rate agressif pour convergence rapide
-    fn aggressive_learning_rate(&self, epoch: usize, _current_loss: f64, current_lr: f64) -> f64 {
-        if epoch < 10 {
-            // Phase initiale: LR élevé
-            current_lr
-        } else if epoch < 30 {
-            // Phase de convergence: LR modéré
-            current_lr * 0.95
-        } else {
-            // Phase finale: LR bas
-            current_lr * 0.9
-        }.max(1e-4) // Minimum plus élevé pour éviter stagnation
-    }
-
-    /// Learning rate conservateur pour stabilité
-    fn conservative_learning_rate(&self, _epoch: usize, _current_loss: f64, current_lr: f64) -> f64 {
-        if _epoch < 30 {
-            current_lr  // Phase initiale stable
-        } else if _epoch < 100 {
-            current_lr * 0.98  // Réduction lente
-        } else {
-            current_lr * 0.95  // Réduction modérée
-        }.max(1e-6)  // Minimum très bas
+        gradients
     }
 
     /// Gradient clipping optimal
-    fn optimal_gradient_clipping(&self, weight_grads: &mut [Array2<f64>], bias_grads: &mut [Array1<f64>], max_norm: f64) {
-        let total_norm: f64 = weight_grads.iter()
-            .map(|grad| grad.mapv(|x| x.powi(2)).sum())
-            .sum::<f64>()
-            + bias_grads.iter()
-                .map(|grad| grad.mapv(|x| x.powi(2)).sum())
-                .sum::<f64>();
-        
-        let total_norm = total_norm.sqrt();
+    fn optimal_gradient_clipping(&self, gradients: &mut Gradients, max_norm: f64) {
+        let total_norm = gradients.norm();
 
         if total_norm > max_norm {
-            let scale = max_norm / total_norm;
-            for grad in weight_grads {
-                *grad = grad.mapv(|x| x * scale);
-            }
-            for grad in bias_grads {
-                *grad = grad.mapv(|x| x * scale);
-            }
+            gradients.scale(max_norm / total_norm);
         }
     }
 
@@ -603,20 +634,108 @@ impl NeuralNetwork {
         total_loss / inputs.len() as f64
     }
 
-    /// Propagation avant avec cache pour la rétropropagation
-    pub fn forward_with_cache(&self, input: &Array1<f64>) -> (Array1<f64>, Vec<(Array1<f64>, Array1<f64>)>) {
+    /// Propagation avant avec cache pour la rétropropagation (selon le mode courant)
+    pub fn forward_with_cache(&self, input: &Array1<f64>) -> (Array1<f64>, LayerCache) {
+        self.forward_cached(input, self.mode)
+    }
+
+    /// Propagation avant avec cache dans le mode demandé
+    ///
+    /// Un échantillon seul ne forme pas un batch: les BatchNorm utilisent ici leurs
+    /// moyennes mobiles.
+    fn forward_cached(&self, input: &Array1<f64>, mode: Mode) -> (Array1<f64>, LayerCache) {
         let mut activations = Vec::new();
         let mut current_activation = input.clone();
+        let mut rng = None;
+        let last = self.layers.len().saturating_sub(1);
         
-        for layer in &self.layers {
+        for (i, layer) in self.layers.iter().enumerate() {
             let z = &layer.weights.dot(&current_activation) + &layer.biases;
-            activations.push((current_activation.clone(), z.clone()));
-            current_activation = layer.activation.activate(&z);
+            let (z, normalization) = match &layer.normalization {
+                Some(norm) => {
+                    let (normalized, norm_cache, _) = norm.forward(&z.insert_axis(Axis(0)), false);
+                    (normalized.row(0).to_owned(), Some(norm_cache))
+                }
+                None => (z, None),
+            };
+            let input = std::mem::replace(&mut current_activation, layer.activation.activate(&z));
+            
+            let mut dropout_mask = None;
+            if let Some(dropout) = &layer.dropout
+                && mode == Mode::Training
+                && i < last
+            {
+                let rng = rng.get_or_insert_with(|| self.dropout_rng());
+                let (dropped, mask) = dropout.apply(&current_activation, rng);
+                current_activation = dropped;
+                dropout_mask = Some(mask);
+            }
+            
+            activations.push(CachedLayer { input, z, normalization, dropout_mask });
         }
         
         (current_activation, activations)
     }
 
+    /// Propagation avant d'un batch (une ligne par échantillon) avec cache
+    ///
+    /// En entraînement, les BatchNorm normalisent avec les statistiques du batch (du batch
+    /// entier avec `shared`), retournées pour la mise à jour des moyennes mobiles.
+    fn forward_batch(
+        &self,
+        inputs: &Array2<f64>,
+        mode: Mode,
+        rng: &mut StdRng,
+        shared: Option<NormalizationPart>,
+    ) -> (Array2<f64>, Vec<BatchCache>, BatchStatistics) {
+        let mut caches = Vec::with_capacity(self.layers.len());
+        let mut statistics = Vec::with_capacity(self.layers.len());
+        let mut current = inputs.clone();
+        let last = self.layers.len().saturating_sub(1);
+
+        for (i, layer) in self.layers.iter().enumerate() {
+            let mut z = current.dot(&layer.weights.t()) + &layer.biases;
+            let mut normalization = None;
+            let mut batch_moments = None;
+            if let Some(norm) = &layer.normalization {
+                let (normalized, norm_cache, moments) = match shared {
+                    Some(part) => part.forward(i, norm, &z),
+                    None => norm.forward(&z, mode == Mode::Training),
+                };
+                z = normalized;
+                normalization = Some(norm_cache);
+                batch_moments = moments;
+            }
+            let input = std::mem::replace(&mut current, layer.activation.activate_rows(&z));
+
+            let mut dropout_mask = None;
+            if let Some(dropout) = &layer.dropout
+                && mode == Mode::Training
+                && i < last
+            {
+                let mut mask = Array2::zeros(current.dim());
+                for (mut row, mut mask_row) in current.rows_mut().into_iter().zip(mask.rows_mut()) {
+                    let (dropped, row_mask) = dropout.apply(&row.to_owned(), rng);
+                    row.assign(&dropped);
+                    mask_row.assign(&row_mask);
+                }
+                dropout_mask = Some(mask);
+            }
+
+            caches.push(BatchCache { input, z, normalization, dropout_mask });
+            statistics.push(batch_moments);
+        }
+
+        (current, caches, statistics)
+    }
+
+    /// Générateur des masques de dropout d'une propagation: dérivé de la graine et du
+    /// nombre de tirages déjà effectués, donc reproductible pour une même graine
+    fn dropout_rng(&self) -> StdRng {
+        let draw = self.dropout_draws.fetch_add(1, Ordering::Relaxed);
+        StdRng::seed_from_u64(self.seed ^ draw.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+    }
+
     /// Calcule la loss MSE (Mean Squared Error)
     pub fn mse_loss(&self, output: &Array1<f64>, target: &Array1<f64>) -> f64 {
         output.iter().zip(target.iter())
@@ -632,145 +751,87 @@ impl NeuralNetwork {
         epochs: usize,
         batch_size: usize,
     ) -> TrainingMetrics {
-        let mut optimizer = SGD::new(self.learning_rate);
-        
-        println!("🎯 Entraînement optimal - {} samples", inputs.len());
-        println!("   Architecture: {} couches", self.layers.len());
-        println!("   Paramètres: {} epochs, batch: {}", epochs, batch_size);
-        
-        for epoch in 0..epochs {
-            let mut epoch_loss = 0.0;
-            let mut batches_processed = 0;
-            
-            let mut indices: Vec<usize> = (0..inputs.len()).collect();
-            Self::shuffle_indices_fast(&mut indices);
-            
-            for batch_start in (0..inputs.len()).step_by(batch_size) {
-                let batch_end = (batch_start + batch_size).min(inputs.len());
-                let batch_loss = self.process_batch_optimal(
-                    inputs, 
-                    targets, 
-                    &indices[batch_start..batch_end],
-                    &mut optimizer
-                );
-                epoch_loss += batch_loss;
-                batches_processed += 1;
-            }
-            
-            if batches_processed > 0 {
-                let avg_loss = epoch_loss / batches_processed as f64;
-                
-                // Learning rate adaptatif optimal
-                optimizer.learning_rate = self.optimal_learning_rate(epoch, avg_loss, optimizer.learning_rate);
-                
-                let improved = self.metrics.update(avg_loss, 0.0, optimizer.learning_rate);
-                
-                if epoch % 25 == 0 || epoch == epochs - 1 || improved {
-                    let marker = if improved { "📈" } else { "  " };
-                    println!("Epoch {:3} {} Loss: {:.6} | LR: {:.5}", 
-                        epoch, marker, avg_loss, optimizer.learning_rate);
-                }
-                
-                // Early stopping optimal
-                if self.metrics.patience_counter > 35 {
-                    println!("⏹️  Arrêt optimal à epoch {}", epoch);
-                    break;
-                }
-            }
-        }
-        
-        println!("✅ Entraînement optimal terminé! Best loss: {:.6}", self.metrics.best_loss);
-        self.metrics.clone()
+        Trainer::new(TrainingConfig { epochs, batch_size, ..TrainingConfig::optimal() }).fit(self, inputs, targets, None)
     }
+}
 
-    /// Traitement de batch optimal
-    fn process_batch_optimal(
-        &mut self,
-        inputs: &[Array1<f64>],
-        targets: &[Array1<f64>],
-        batch_indices: &[usize],
-        optimizer: &mut SGD,
-    ) -> f64 {
-        let batch_size = batch_indices.len();
-        let mut total_loss = 0.0;
-        
-        let mut weight_gradients: Vec<Array2<f64>> = self.layers.iter()
-            .map(|layer| Array2::zeros((layer.output_size, layer.input_size)))
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples(input_size: usize, output_size: usize) -> (Vec<Array1<f64>>, Vec<Array1<f64>>) {
+        let inputs = (0..9)
+            .map(|i| Array1::from_shape_fn(input_size, |j| ((i * 7 + j * 5) % 11) as f64 / 4.0 - 1.3))
             .collect();
-            
-        let mut bias_gradients: Vec<Array1<f64>> = self.layers.iter()
-            .map(|layer| Array1::zeros(layer.output_size))
+        let targets = (0..9)
+            .map(|i| Array1::from_shape_fn(output_size, |j| if (i + j) % output_size == 0 { 1.0 } else { 0.0 }))
             .collect();
+        (inputs, targets)
+    }
 
-        for &idx in batch_indices {
-            let input = &inputs[idx];
-            let target = &targets[idx];
-            
-            let (output, activations) = self.forward_with_cache(input);
-            total_loss += self.mse_loss(&output, target);
-            
-            let gradients = self.backward_optimal(&output, target, &activations);
-            
-            for (i, (wg, bg)) in gradients.iter().enumerate() {
-                weight_gradients[i] = &weight_gradients[i] + wg;
-                bias_gradients[i] = &bias_gradients[i] + bg;
-            }
-        }
-
-        // Gradient clipping optimal
-        self.optimal_gradient_clipping(&mut weight_gradients, &mut bias_gradients, 2.5);
-
-        for (i, layer) in self.layers.iter_mut().enumerate() {
-            let avg_weight_grad = &weight_gradients[i] / batch_size as f64;
-            let avg_bias_grad = &bias_gradients[i] / batch_size as f64;
-            
-            layer.weights = optimizer.update_weights(&layer.weights, &avg_weight_grad);
-            layer.biases = optimizer.update_biases(&layer.biases, &avg_bias_grad);
+    /// Compare `gradient_scale · gradients` aux différences finies centrées de la perte totale
+    fn check_gradients(mut network: NeuralNetwork, loss: &dyn Loss, input_size: usize, output_size: usize) {
+        let (inputs, targets) = samples(input_size, output_size);
+        let indices: Vec<usize> = (0..inputs.len()).collect();
+        let (_, gradients) = network.accumulate_gradients(loss, &inputs, &targets, &indices, Mode::Training);
+        let scale = loss.gradient_scale(output_size);
+        let parameters = network.parameters();
+        let step = 1e-5;
+
+        for (i, &analytic) in gradients.values().enumerate() {
+            let mut total_loss = |value: f64| {
+                let mut shifted = parameters.clone();
+                shifted[i] = value;
+                network.set_parameters(&shifted);
+                network.accumulate_gradients(loss, &inputs, &targets, &indices, Mode::Training).0
+            };
+            let numeric = (total_loss(parameters[i] + step) - total_loss(parameters[i] - step)) / (2.0 * step);
+            assert!(
+                (numeric - scale * analytic).abs() < 1e-6 * (1.0 + numeric.abs()),
+                "paramètre {}: {} analytique, {} numérique", i, scale * analytic, numeric
+            );
         }
-
-        total_loss / batch_size as f64
     }
 
-    /// Rétropropagation optimale
-    fn backward_optimal(
-        &self,
-        output: &Array1<f64>,
-        target: &Array1<f64>,
-        activations: &[(Array1<f64>, Array1<f64>)],
-    ) -> Vec<(Array2<f64>, Array1<f64>)> {
-        let mut gradients = Vec::new();
-        let mut delta = output - target;
+    #[test]
+    fn softmax_output_gradients_match_finite_differences() {
+        let build = || {
+            let mut network = NeuralNetwork::new(0.01);
+            network
+                .add_layer(4, 5, Activation::Tanh)
+                .add_layer(5, 3, Activation::Softmax);
+            network
+        };
+        // Mse dérive à travers la jacobienne du softmax, l'entropie croisée directement les logits
+        let network = build();
+        let mut same = build();
+        same.layers = network.layers.clone();
+        check_gradients(network, &Mse, 4, 3);
+        check_gradients(same, &CategoricalCrossEntropy, 4, 3);
+    }
 
-        for (i, layer) in self.layers.iter().enumerate().rev() {
-            let (ref input, ref z) = activations[i];
-            
-            let activation_derivative = layer.activation.derivative(z);
-            delta = &delta * &activation_derivative;
-            
-            let weight_gradient = {
-                let delta_2d = delta.view().insert_axis(ndarray::Axis(1));
-                let input_2d = input.view().insert_axis(ndarray::Axis(0));
-                delta_2d.dot(&input_2d)
-            };
-                
-            gradients.push((weight_gradient, delta.clone()));
-            
-            if i > 0 {
-                delta = layer.weights.t().dot(&delta);
-            }
-        }
-        
-        gradients.reverse();
-        gradients
+    #[test]
+    fn normalization_gradients_match_finite_differences() {
+        let mut network = NeuralNetwork::new(0.01);
+        network
+            .add_layer(4, 6, Activation::Tanh)
+            .add_normalization(NormalizationKind::batch())
+            .add_layer(6, 5, Activation::Elu { alpha: 1.0 })
+            .add_normalization(NormalizationKind::Layer)
+            .add_layer(5, 2, Activation::Linear);
+        check_gradients(network, &Huber::new(0.5), 4, 2);
     }
 
-    /// Learning rate optimal
-    fn optimal_learning_rate(&self, epoch: usize, _current_loss: f64, current_lr: f64) -> f64 {
-        match epoch {
-            0..=20 => current_lr,                    // Phase initiale stable
-            21..=60 => current_lr * 0.97,            // Réduction progressive
-            61..=90 => current_lr * 0.95,            // Réduction accélérée
-            _ => current_lr * 0.92,                  // Phase finale
-        }.max(1e-5) // Minimum optimal
+    #[test]
+    fn prelu_slope_gradients_match_finite_differences() {
+        let mut network = NeuralNetwork::new(0.01);
+        network
+            .add_layer(4, 6, Activation::prelu())
+            .add_layer(6, 2, Activation::prelu());
+        // Pentes nulles: le gradient des pentes ne doit pas dépendre d'une division par la pente
+        for slope in network.layers.iter_mut().flat_map(|layer| layer.activation.parameters_mut()) {
+            *slope = 0.0;
+        }
+        check_gradients(network, &Mse, 4, 2);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,211 @@
+use ndarray::{Array1, Array2, Axis};
+use serde::{Deserialize, Serialize};
+
+/// Moyenne et variance par neurone
+pub type Moments = (Array1<f64>, Array1<f64>);
+
+/// Variante de normalisation
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum NormalizationKind {
+    /// Statistiques de chaque neurone sur le batch, moyennes mobiles en inférence
+    Batch { momentum: f64 },
+    /// Statistiques de chaque échantillon sur les neurones de la couche
+    Layer,
+}
+
+impl NormalizationKind {
+    /// BatchNorm avec un momentum de 0.9 pour les moyennes mobiles
+    pub fn batch() -> Self {
+        Self::Batch { momentum: 0.9 }
+    }
+}
+
+/// Normalisation des pré-activations d'une couche, suivie d'un gain γ et d'un décalage β appris
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "NormalizationData", into = "NormalizationData")]
+pub struct Normalization {
+    pub kind: NormalizationKind,
+    pub gamma: Array1<f64>,
+    pub beta: Array1<f64>,
+    pub epsilon: f64,
+    /// Moyenne et variance mobiles (BatchNorm uniquement)
+    pub running_mean: Array1<f64>,
+    pub running_var: Array1<f64>,
+}
+
+/// Valeurs mémorisées par la propagation avant pour la rétropropagation
+#[derive(Debug, Clone)]
+pub struct NormalizationCache {
+    normalized: Array2<f64>,
+    /// 1/√(σ² + ε), diffusable sur `normalized`
+    inv_std: Array2<f64>,
+    /// Axe des statistiques si elles dépendent des entrées, `None` si elles sont fixes
+    axis: Option<Axis>,
+}
+
+impl Normalization {
+    /// Crée une normalisation pour `size` neurones (γ = 1, β = 0)
+    pub fn new(kind: NormalizationKind, size: usize) -> Self {
+        Self {
+            kind,
+            gamma: Array1::ones(size),
+            beta: Array1::zeros(size),
+            epsilon: 1e-5,
+            running_mean: Array1::zeros(size),
+            running_var: Array1::ones(size),
+        }
+    }
+
+    /// BatchNorm par défaut
+    pub fn batch(size: usize) -> Self {
+        Self::new(NormalizationKind::batch(), size)
+    }
+
+    /// LayerNorm
+    pub fn layer(size: usize) -> Self {
+        Self::new(NormalizationKind::Layer, size)
+    }
+
+    /// Normalise un batch de pré-activations (une ligne par échantillon)
+    ///
+    /// Avec `batch_statistics`, une BatchNorm utilise la moyenne et la variance du batch
+    /// et les retourne pour mettre à jour les moyennes mobiles; sinon elle utilise ces
+    /// dernières. La LayerNorm calcule toujours les statistiques de chaque échantillon.
+    pub fn forward(
+        &self,
+        z: &Array2<f64>,
+        batch_statistics: bool,
+    ) -> (Array2<f64>, NormalizationCache, Option<Moments>) {
+        let (axis, statistics) = match self.kind {
+            NormalizationKind::Batch { .. } if !batch_statistics => (None, None),
+            NormalizationKind::Batch { .. } => (Some(Axis(0)), Some(moments(z, Axis(0)))),
+            NormalizationKind::Layer => (Some(Axis(1)), Some(moments(z, Axis(1)))),
+        };
+
+        let (mean, var) = match (axis, &statistics) {
+            (Some(axis), Some((mean, var))) => (mean.clone().insert_axis(axis), var.clone().insert_axis(axis)),
+            _ => (self.running_mean.clone().insert_axis(Axis(0)), self.running_var.clone().insert_axis(Axis(0))),
+        };
+        let inv_std = var.mapv(|v| 1.0 / (v + self.epsilon).sqrt());
+        let normalized = (z - &mean) * &inv_std;
+        let output = &normalized * &self.gamma + &self.beta;
+
+        let batch_moments = if axis == Some(Axis(0)) { statistics } else { None };
+        (output, NormalizationCache { normalized, inv_std, axis }, batch_moments)
+    }
+
+    /// Gradients par rapport aux pré-activations, à γ et à β
+    pub fn backward(&self, delta: &Array2<f64>, cache: &NormalizationCache) -> (Array2<f64>, Array1<f64>, Array1<f64>) {
+        let gamma_gradient = (delta * &cache.normalized).sum_axis(Axis(0));
+        let beta_gradient = delta.sum_axis(Axis(0));
+        let normalized_delta = delta * &self.gamma;
+
+        let input_delta = match cache.axis {
+            None => &normalized_delta * &cache.inv_std,
+            Some(axis) => {
+                // Les statistiques dépendent des entrées: dx = (dx̂ − moy(dx̂) − x̂·moy(dx̂·x̂)) / σ
+                let count = normalized_delta.len_of(axis) as f64;
+                let mean_delta = (normalized_delta.sum_axis(axis) / count).insert_axis(axis);
+                let mean_projection = ((&normalized_delta * &cache.normalized).sum_axis(axis) / count).insert_axis(axis);
+                (&normalized_delta - &mean_delta - &cache.normalized * &mean_projection) * &cache.inv_std
+            }
+        };
+
+        (input_delta, gamma_gradient, beta_gradient)
+    }
+
+    /// Normalise une part de batch avec les moments de la BatchNorm sur le batch entier
+    ///
+    /// Le cache conserve la dépendance des statistiques aux entrées: `backward_with_sums`
+    /// rétropropage ensuite à travers elles.
+    pub fn forward_with_moments(&self, z: &Array2<f64>, moments: &Moments) -> (Array2<f64>, NormalizationCache) {
+        let (mean, var) = moments;
+        let inv_std = var.mapv(|v| 1.0 / (v + self.epsilon).sqrt()).insert_axis(Axis(0));
+        let normalized = (z - &mean.view().insert_axis(Axis(0))) * &inv_std;
+        let output = &normalized * &self.gamma + &self.beta;
+        (output, NormalizationCache { normalized, inv_std, axis: Some(Axis(0)) })
+    }
+
+    /// Sommes sur une part de batch de dx̂ et dx̂·x̂, seuls termes de la rétropropagation
+    /// d'une BatchNorm qui couplent les échantillons
+    pub fn backward_sums(&self, delta: &Array2<f64>, cache: &NormalizationCache) -> (Array1<f64>, Array1<f64>) {
+        let normalized_delta = delta * &self.gamma;
+        let projection = (&normalized_delta * &cache.normalized).sum_axis(Axis(0));
+        (normalized_delta.sum_axis(Axis(0)), projection)
+    }
+
+    /// Rétropropagation d'une part de batch à partir des sommes `backward_sums` du batch
+    /// entier, qui compte `count` échantillons
+    pub fn backward_with_sums(
+        &self,
+        delta: &Array2<f64>,
+        cache: &NormalizationCache,
+        sums: &(Array1<f64>, Array1<f64>),
+        count: usize,
+    ) -> (Array2<f64>, Array1<f64>, Array1<f64>) {
+        let gamma_gradient = (delta * &cache.normalized).sum_axis(Axis(0));
+        let beta_gradient = delta.sum_axis(Axis(0));
+        let normalized_delta = delta * &self.gamma;
+
+        let count = count as f64;
+        let mean_delta = (&sums.0 / count).insert_axis(Axis(0));
+        let mean_projection = (&sums.1 / count).insert_axis(Axis(0));
+        let input_delta = (&normalized_delta - &mean_delta - &cache.normalized * &mean_projection) * &cache.inv_std;
+
+        (input_delta, gamma_gradient, beta_gradient)
+    }
+
+    /// Reporte la moyenne et la variance d'un batch sur les moyennes mobiles
+    pub fn update_running_statistics(&mut self, mean: &Array1<f64>, var: &Array1<f64>) {
+        if let NormalizationKind::Batch { momentum } = self.kind {
+            self.running_mean = &self.running_mean * momentum + mean * (1.0 - momentum);
+            self.running_var = &self.running_var * momentum + var * (1.0 - momentum);
+        }
+    }
+}
+
+/// Moyenne et variance (biaisée) le long d'un axe
+pub(super) fn moments(z: &Array2<f64>, axis: Axis) -> Moments {
+    let count = z.len_of(axis) as f64;
+    let mean = z.sum_axis(axis) / count;
+    let centered = z - &mean.clone().insert_axis(axis);
+    let var = centered.mapv(|v| v * v).sum_axis(axis) / count;
+    (mean, var)
+}
+
+// Représentation sérialisée (vecteurs simples)
+#[derive(Clone, Serialize, Deserialize)]
+struct NormalizationData {
+    kind: NormalizationKind,
+    gamma: Vec<f64>,
+    beta: Vec<f64>,
+    epsilon: f64,
+    running_mean: Vec<f64>,
+    running_var: Vec<f64>,
+}
+
+impl From<Normalization> for NormalizationData {
+    fn from(normalization: Normalization) -> Self {
+        Self {
+            kind: normalization.kind,
+            gamma: normalization.gamma.to_vec(),
+            beta: normalization.beta.to_vec(),
+            epsilon: normalization.epsilon,
+            running_mean: normalization.running_mean.to_vec(),
+            running_var: normalization.running_var.to_vec(),
+        }
+    }
+}
+
+impl From<NormalizationData> for Normalization {
+    fn from(data: NormalizationData) -> Self {
+        Self {
+            kind: data.kind,
+            gamma: Array1::from_vec(data.gamma),
+            beta: Array1::from_vec(data.beta),
+            epsilon: data.epsilon,
+            running_mean: Array1::from_vec(data.running_mean),
+            running_var: Array1::from_vec(data.running_var),
+        }
+    }
+}
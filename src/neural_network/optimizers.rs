@@ -1,4 +1,117 @@
-use ndarray::{Array1, Array2};
+use std::collections::HashMap;
+
+use ndarray::{Array, Array1, Array2, Dimension};
+use serde::{Deserialize, Serialize};
+use super::{Gradients, Layer};
+
+/// Nature d'un tenseur de paramètres
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParamKind {
+    Weights,
+    Biases,
+    /// Gain de la normalisation
+    Gamma,
+    /// Décalage de la normalisation
+    Beta,
+    /// Pentes PReLU
+    Slopes,
+}
+
+/// Identifiant d'un tenseur de paramètres (couche + nature), clé de l'état des optimiseurs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParamId {
+    pub layer: usize,
+    pub kind: ParamKind,
+}
+
+/// Optimiseur conservant un état propre à chaque tenseur de paramètres
+pub trait Optimizer {
+    /// Taux d'apprentissage courant
+    fn learning_rate(&self) -> f64;
+
+    /// Modifie le taux d'apprentissage (scheduling)
+    fn set_learning_rate(&mut self, learning_rate: f64);
+
+    /// Met à jour un tenseur de paramètres à partir de son gradient
+    fn update(&mut self, id: ParamId, params: &mut [f64], grads: &[f64]);
+
+    /// Appelé une fois avant chaque pas d'optimisation
+    fn begin_step(&mut self) {}
+
+    /// Applique un pas à toutes les couches du réseau
+    fn step(&mut self, layers: &mut [Layer], gradients: &Gradients) {
+        self.begin_step();
+        for (i, (layer, grads)) in layers.iter_mut().zip(&gradients.layers).enumerate() {
+            update_array(self, ParamId { layer: i, kind: ParamKind::Weights }, &mut layer.weights, &grads.weights);
+            update_array(self, ParamId { layer: i, kind: ParamKind::Biases }, &mut layer.biases, &grads.biases);
+            if let Some(normalization) = &mut layer.normalization {
+                update_array(self, ParamId { layer: i, kind: ParamKind::Gamma }, &mut normalization.gamma, &grads.gamma);
+                update_array(self, ParamId { layer: i, kind: ParamKind::Beta }, &mut normalization.beta, &grads.beta);
+            }
+            let slopes = layer.activation.parameters_mut();
+            if !slopes.is_empty()
+                && let Some(slope_grads) = grads.slopes.as_slice()
+            {
+                self.update(ParamId { layer: i, kind: ParamKind::Slopes }, slopes, slope_grads);
+            }
+        }
+    }
+}
+
+fn update_array<O: Optimizer + ?Sized, D: Dimension>(
+    optimizer: &mut O,
+    id: ParamId,
+    params: &mut Array<f64, D>,
+    grads: &Array<f64, D>,
+) {
+    if !params.is_standard_layout() {
+        *params = params.as_standard_layout().into_owned();
+    }
+    let grads = grads.as_standard_layout();
+    if let (Some(params), Some(grads)) = (params.as_slice_mut(), grads.as_slice()) {
+        optimizer.update(id, params, grads);
+    }
+}
+
+/// Choix d'optimiseur sérialisable, instancié au début de chaque entraînement
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum OptimizerKind {
+    #[default]
+    Sgd,
+    Momentum { momentum: f64 },
+    Nesterov { momentum: f64 },
+    Adam { beta1: f64, beta2: f64, epsilon: f64 },
+    AdamW { beta1: f64, beta2: f64, epsilon: f64, weight_decay: f64 },
+    RmsProp { decay: f64, epsilon: f64 },
+    Adagrad { epsilon: f64 },
+}
+
+impl OptimizerKind {
+    /// Adam avec les hyperparamètres usuels
+    pub fn adam() -> Self {
+        Self::Adam { beta1: 0.9, beta2: 0.999, epsilon: 1e-8 }
+    }
+
+    /// AdamW avec les hyperparamètres usuels
+    pub fn adamw(weight_decay: f64) -> Self {
+        Self::AdamW { beta1: 0.9, beta2: 0.999, epsilon: 1e-8, weight_decay }
+    }
+
+    /// Crée l'optimiseur correspondant
+    pub fn build(&self, learning_rate: f64) -> Box<dyn Optimizer> {
+        match *self {
+            Self::Sgd => Box::new(SGD::new(learning_rate)),
+            Self::Momentum { momentum } => Box::new(Momentum::new(learning_rate, momentum)),
+            Self::Nesterov { momentum } => Box::new(Momentum::nesterov(learning_rate, momentum)),
+            Self::Adam { beta1, beta2, epsilon } => Box::new(Adam::new(learning_rate, beta1, beta2, epsilon)),
+            Self::AdamW { beta1, beta2, epsilon, weight_decay } => {
+                Box::new(Adam::new(learning_rate, beta1, beta2, epsilon).with_decoupled_weight_decay(weight_decay))
+            }
+            Self::RmsProp { decay, epsilon } => Box::new(RmsProp::new(learning_rate, decay, epsilon)),
+            Self::Adagrad { epsilon } => Box::new(Adagrad::new(learning_rate, epsilon)),
+        }
+    }
+}
 
 /// Optimiseur SGD (Stochastic Gradient Descent)
 pub struct SGD {
@@ -20,4 +133,190 @@ impl SGD {
     pub fn update_biases(&self, biases: &Array1<f64>, gradients: &Array1<f64>) -> Array1<f64> {
         biases - &(gradients * self.learning_rate)
     }
-}
\ No newline at end of file
+}
+
+impl Optimizer for SGD {
+    fn learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.learning_rate = learning_rate;
+    }
+
+    fn update(&mut self, _id: ParamId, params: &mut [f64], grads: &[f64]) {
+        for (p, g) in params.iter_mut().zip(grads) {
+            *p -= self.learning_rate * g;
+        }
+    }
+}
+
+/// SGD avec momentum classique ou de Nesterov
+pub struct Momentum {
+    pub learning_rate: f64,
+    pub momentum: f64,
+    pub nesterov: bool,
+    velocities: HashMap<ParamId, Vec<f64>>,
+}
+
+impl Momentum {
+    /// Momentum classique: v = μv + g, θ -= lr·v
+    pub fn new(learning_rate: f64, momentum: f64) -> Self {
+        Self { learning_rate, momentum, nesterov: false, velocities: HashMap::new() }
+    }
+
+    /// Momentum de Nesterov: θ -= lr·(g + μv)
+    pub fn nesterov(learning_rate: f64, momentum: f64) -> Self {
+        Self { nesterov: true, ..Self::new(learning_rate, momentum) }
+    }
+}
+
+impl Optimizer for Momentum {
+    fn learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.learning_rate = learning_rate;
+    }
+
+    fn update(&mut self, id: ParamId, params: &mut [f64], grads: &[f64]) {
+        let velocity = self.velocities.entry(id).or_insert_with(|| vec![0.0; params.len()]);
+        for ((p, g), v) in params.iter_mut().zip(grads).zip(velocity.iter_mut()) {
+            *v = self.momentum * *v + g;
+            let direction = if self.nesterov { g + self.momentum * *v } else { *v };
+            *p -= self.learning_rate * direction;
+        }
+    }
+}
+
+/// Adam, ou AdamW avec une décroissance des poids découplée
+pub struct Adam {
+    pub learning_rate: f64,
+    pub beta1: f64,
+    pub beta2: f64,
+    pub epsilon: f64,
+    /// Décroissance appliquée directement aux poids (AdamW), jamais aux biais
+    pub weight_decay: f64,
+    step: i32,
+    first_moments: HashMap<ParamId, Vec<f64>>,
+    second_moments: HashMap<ParamId, Vec<f64>>,
+}
+
+impl Adam {
+    /// Crée un optimiseur Adam
+    pub fn new(learning_rate: f64, beta1: f64, beta2: f64, epsilon: f64) -> Self {
+        Self {
+            learning_rate,
+            beta1,
+            beta2,
+            epsilon,
+            weight_decay: 0.0,
+            step: 0,
+            first_moments: HashMap::new(),
+            second_moments: HashMap::new(),
+        }
+    }
+
+    /// Transforme Adam en AdamW
+    pub fn with_decoupled_weight_decay(mut self, weight_decay: f64) -> Self {
+        self.weight_decay = weight_decay;
+        self
+    }
+}
+
+impl Optimizer for Adam {
+    fn learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.learning_rate = learning_rate;
+    }
+
+    fn begin_step(&mut self) {
+        self.step += 1;
+    }
+
+    fn update(&mut self, id: ParamId, params: &mut [f64], grads: &[f64]) {
+        let step = self.step.max(1);
+        let m = self.first_moments.entry(id).or_insert_with(|| vec![0.0; params.len()]);
+        let v = self.second_moments.entry(id).or_insert_with(|| vec![0.0; params.len()]);
+        let m_correction = 1.0 - self.beta1.powi(step);
+        let v_correction = 1.0 - self.beta2.powi(step);
+        let decay = if id.kind == ParamKind::Weights { self.weight_decay } else { 0.0 };
+
+        for (i, (p, g)) in params.iter_mut().zip(grads).enumerate() {
+            m[i] = self.beta1 * m[i] + (1.0 - self.beta1) * g;
+            v[i] = self.beta2 * v[i] + (1.0 - self.beta2) * g * g;
+            let m_hat = m[i] / m_correction;
+            let v_hat = v[i] / v_correction;
+            *p -= self.learning_rate * (m_hat / (v_hat.sqrt() + self.epsilon) + decay * *p);
+        }
+    }
+}
+
+/// RMSProp: gradient normalisé par une moyenne mobile des carrés
+pub struct RmsProp {
+    pub learning_rate: f64,
+    pub decay: f64,
+    pub epsilon: f64,
+    mean_squares: HashMap<ParamId, Vec<f64>>,
+}
+
+impl RmsProp {
+    /// Crée un optimiseur RMSProp
+    pub fn new(learning_rate: f64, decay: f64, epsilon: f64) -> Self {
+        Self { learning_rate, decay, epsilon, mean_squares: HashMap::new() }
+    }
+}
+
+impl Optimizer for RmsProp {
+    fn learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.learning_rate = learning_rate;
+    }
+
+    fn update(&mut self, id: ParamId, params: &mut [f64], grads: &[f64]) {
+        let mean_square = self.mean_squares.entry(id).or_insert_with(|| vec![0.0; params.len()]);
+        for ((p, g), s) in params.iter_mut().zip(grads).zip(mean_square.iter_mut()) {
+            *s = self.decay * *s + (1.0 - self.decay) * g * g;
+            *p -= self.learning_rate * g / (s.sqrt() + self.epsilon);
+        }
+    }
+}
+
+/// Adagrad: gradient normalisé par la somme cumulée des carrés
+pub struct Adagrad {
+    pub learning_rate: f64,
+    pub epsilon: f64,
+    sum_squares: HashMap<ParamId, Vec<f64>>,
+}
+
+impl Adagrad {
+    /// Crée un optimiseur Adagrad
+    pub fn new(learning_rate: f64, epsilon: f64) -> Self {
+        Self { learning_rate, epsilon, sum_squares: HashMap::new() }
+    }
+}
+
+impl Optimizer for Adagrad {
+    fn learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+
+    fn set_learning_rate(&mut self, learning_rate: f64) {
+        self.learning_rate = learning_rate;
+    }
+
+    fn update(&mut self, id: ParamId, params: &mut [f64], grads: &[f64]) {
+        let sum_square = self.sum_squares.entry(id).or_insert_with(|| vec![0.0; params.len()]);
+        for ((p, g), s) in params.iter_mut().zip(grads).zip(sum_square.iter_mut()) {
+            *s += g * g;
+            *p -= self.learning_rate * g / (s.sqrt() + self.epsilon);
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use std::sync::{Barrier, Mutex};
+use std::thread;
+use ndarray::{Array1, Array2, Axis};
+use super::normalization::{moments, Moments, NormalizationCache};
+use super::{Activation, Gradients, Mode, NeuralNetwork, Normalization, NormalizationKind};
+
+impl NeuralNetwork {
+    /// Gradients d'un batch répartis entre les threads configurés par `set_threads`
+    ///
+    /// Le batch est découpé en parts contiguës, une par thread, chacune avec son propre
+    /// générateur de dropout tiré dans l'ordre des parts. Pertes et gradients sont sommés
+    /// dans ce même ordre: à nombre de threads fixé, le résultat ne dépend pas de
+    /// l'ordonnancement. En entraînement, les BatchNorm mettent en commun moments et
+    /// sommes de rétropropagation (voir `SharedNormalization`): le résultat est celui du
+    /// batch entier propagé d'un bloc, au dropout près.
+    pub(super) fn parallel_gradients<F>(
+        &self,
+        inputs: &[Array1<f64>],
+        targets: &[Array1<f64>],
+        indices: &[usize],
+        mode: Mode,
+        objective: &F,
+    ) -> (f64, Gradients)
+    where
+        F: Fn(usize, &Array1<f64>, &Array1<f64>, &Array1<f64>, &Activation) -> (f64, Array1<f64>) + Sync,
+    {
+        let chunk_size = indices.len().div_ceil(self.threads);
+        let chunks: Vec<&[usize]> = indices.chunks(chunk_size).collect();
+        let rngs: Vec<_> = chunks.iter().map(|_| self.dropout_rng()).collect();
+        let shared = (mode == Mode::Training)
+            .then(|| SharedNormalization::new(self.layers.len(), chunks.len(), indices.len()));
+
+        let parts: Vec<(f64, Gradients)> = thread::scope(|scope| {
+            let workers: Vec<_> = chunks.iter().zip(rngs).enumerate()
+                .map(|(part, (&chunk, mut rng))| {
+                    let normalization = shared.as_ref().map(|shared| NormalizationPart { shared, part });
+                    scope.spawn(move || {
+                        self.batch_gradients(inputs, targets, chunk, mode, objective, &mut rng, normalization)
+                    })
+                })
+                .collect();
+            workers.into_iter()
+                .map(|worker| worker.join().expect("thread de calcul des gradients"))
+                .collect()
+        });
+
+        // Chaque part porte déjà les moments du batch entier
+        let mut parts = parts.into_iter();
+        let (mut total_loss, mut gradients) = parts.next().expect("batch non vide");
+        for (loss, part) in parts {
+            total_loss += loss;
+            gradients.accumulate(&part);
+        }
+
+        (total_loss, gradients)
+    }
+}
+
+/// Sommes (dx̂, dx̂·x̂) d'une BatchNorm sur une part de batch
+type BackwardSums = (Array1<f64>, Array1<f64>);
+
+/// Une valeur par part, publiée avant la barrière
+type Slots<T> = Mutex<Vec<Option<T>>>;
+
+/// Statistiques des BatchNorm mises en commun par les threads d'un même batch
+///
+/// À chaque BatchNorm, chaque part publie ses moments (propagation avant) puis ses
+/// sommes de rétropropagation, attend les autres, et combine toutes les parts dans
+/// leur ordre. Un emplacement par couche et par sens: aucun n'est réutilisé.
+pub(super) struct SharedNormalization {
+    barrier: Barrier,
+    /// Nombre d'échantillons du batch entier
+    count: usize,
+    /// Taille et moments de chaque part
+    moments: Vec<Slots<(usize, Moments)>>,
+    sums: Vec<Slots<BackwardSums>>,
+}
+
+impl SharedNormalization {
+    fn new(layers: usize, parts: usize, count: usize) -> Self {
+        Self {
+            barrier: Barrier::new(parts),
+            count,
+            moments: (0..layers).map(|_| Mutex::new(vec![None; parts])).collect(),
+            sums: (0..layers).map(|_| Mutex::new(vec![None; parts])).collect(),
+        }
+    }
+
+    /// Publie la valeur d'une part, attend toutes les autres et les combine
+    fn exchange<T, R>(&self, slots: &Slots<T>, part: usize, value: T, combine: impl FnOnce(Vec<&T>) -> R) -> R {
+        slots.lock().expect("statistiques partagées")[part] = Some(value);
+        self.barrier.wait();
+        let slots = slots.lock().expect("statistiques partagées");
+        combine(slots.iter().map(|slot| slot.as_ref().expect("part publiée")).collect())
+    }
+}
+
+/// Accès d'une part de batch aux statistiques partagées
+#[derive(Clone, Copy)]
+pub(super) struct NormalizationPart<'a> {
+    shared: &'a SharedNormalization,
+    part: usize,
+}
+
+impl NormalizationPart<'_> {
+    /// Normalise la part avec les moments du batch entier (BatchNorm), ou localement (LayerNorm)
+    pub(super) fn forward(
+        &self,
+        layer: usize,
+        normalization: &Normalization,
+        z: &Array2<f64>,
+    ) -> (Array2<f64>, NormalizationCache, Option<Moments>) {
+        if normalization.kind == NormalizationKind::Layer {
+            return normalization.forward(z, true);
+        }
+
+        let local = (z.nrows(), moments(z, Axis(0)));
+        let pooled = self.shared.exchange(&self.shared.moments[layer], self.part, local, |parts| {
+            pool_moments(&parts.iter().map(|(size, moments)| (*size, moments)).collect::<Vec<_>>())
+        });
+        let (output, cache) = normalization.forward_with_moments(z, &pooled);
+        (output, cache, Some(pooled))
+    }
+
+    /// Rétropropage la part avec les sommes du batch entier (BatchNorm), ou localement (LayerNorm)
+    pub(super) fn backward(
+        &self,
+        layer: usize,
+        normalization: &Normalization,
+        delta: &Array2<f64>,
+        cache: &NormalizationCache,
+    ) -> (Array2<f64>, Array1<f64>, Array1<f64>) {
+        if normalization.kind == NormalizationKind::Layer {
+            return normalization.backward(delta, cache);
+        }
+
+        let local = normalization.backward_sums(delta, cache);
+        let sums = self.shared.exchange(&self.shared.sums[layer], self.part, local, |parts| {
+            let mut total = (Array1::zeros(parts[0].0.len()), Array1::zeros(parts[0].1.len()));
+            for (delta_sum, projection_sum) in parts {
+                total.0 += delta_sum;
+                total.1 += projection_sum;
+            }
+            total
+        });
+        normalization.backward_with_sums(delta, cache, &sums, self.shared.count)
+    }
+}
+
+/// Moyenne pondérée des moyennes; variance = moyenne des variances + variance des moyennes
+fn pool_moments(parts: &[(usize, &Moments)]) -> Moments {
+    let total = parts.iter().map(|(size, _)| *size).sum::<usize>() as f64;
+    let mut mean = Array1::zeros(parts[0].1.0.len());
+    for (size, (part_mean, _)) in parts {
+        mean += &(part_mean * (*size as f64 / total));
+    }
+    let mut var = Array1::zeros(mean.len());
+    for (size, (part_mean, part_var)) in parts {
+        let shift = part_mean - &mean;
+        var += &((part_var + &(&shift * &shift)) * (*size as f64 / total));
+    }
+    (mean, var)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::neural_network::{Dropout, LossKind, Mse};
+
+    /// Réseau à deux BatchNorm, une LayerNorm et une sortie PReLU
+    fn network(threads: usize) -> NeuralNetwork {
+        let mut network = NeuralNetwork::new(0.01);
+        network
+            .set_seed(11)
+            .set_threads(threads)
+            .add_layer(5, 8, Activation::Tanh)
+            .add_normalization(NormalizationKind::batch())
+            .add_layer(8, 6, Activation::Relu)
+            .add_normalization(NormalizationKind::Layer)
+            .add_layer(6, 4, Activation::Elu { alpha: 1.0 })
+            .add_normalization(NormalizationKind::batch())
+            .add_layer(4, 2, Activation::prelu());
+        network
+    }
+
+    fn samples() -> (Vec<Array1<f64>>, Vec<Array1<f64>>) {
+        let inputs = (0..23)
+            .map(|i| Array1::from_shape_fn(5, |j| ((i * 7 + j * 5) % 13) as f64 / 4.0 - 1.5))
+            .collect();
+        let targets = (0..23)
+            .map(|i| Array1::from_vec(vec![(i % 3) as f64 - 1.0, ((i * 5) % 7) as f64 / 7.0]))
+            .collect();
+        (inputs, targets)
+    }
+
+    #[test]
+    fn threads_match_single_batch_with_batch_norm() {
+        let (inputs, targets) = samples();
+        let indices: Vec<usize> = (0..inputs.len()).collect();
+        let reference = network(1);
+        let loss = LossKind::Huber { delta: 0.5 }.build();
+        let (expected_loss, expected) =
+            reference.accumulate_gradients(loss.as_ref(), &inputs, &targets, &indices, Mode::Training);
+
+        for threads in [2, 3, 4] {
+            let mut parallel = network(threads);
+            parallel.layers = reference.layers.clone();
+            let (total_loss, gradients) =
+                parallel.accumulate_gradients(loss.as_ref(), &inputs, &targets, &indices, Mode::Training);
+
+            assert!((total_loss - expected_loss).abs() < 1e-10);
+            for (value, reference) in gradients.values().zip(expected.values()) {
+                assert!((value - reference).abs() < 1e-10, "{} threads: {} au lieu de {}", threads, value, reference);
+            }
+            for (statistics, reference) in gradients.batch_statistics.iter().zip(&expected.batch_statistics) {
+                match (statistics, reference) {
+                    (Some((mean, var)), Some((reference_mean, reference_var))) => {
+                        assert!((mean - reference_mean).iter().all(|d| d.abs() < 1e-12));
+                        assert!((var - reference_var).iter().all(|d| d.abs() < 1e-12));
+                    }
+                    (None, None) => {}
+                    _ => panic!("statistiques manquantes avec {} threads", threads),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn threads_are_deterministic_with_dropout() {
+        let (inputs, targets) = samples();
+        let indices: Vec<usize> = (0..inputs.len()).collect();
+        let with_dropout = || {
+            let mut network = network(3);
+            network.layers[0].dropout = Some(Dropout::new(0.3));
+            network.layers[2].dropout = Some(Dropout::new(0.3));
+            network
+        };
+        let first = with_dropout();
+        let mut second = with_dropout();
+        second.layers = first.layers.clone();
+
+        let (first_loss, first_gradients) =
+            first.accumulate_gradients(&Mse, &inputs, &targets, &indices, Mode::Training);
+        let (second_loss, second_gradients) =
+            second.accumulate_gradients(&Mse, &inputs, &targets, &indices, Mode::Training);
+
+        assert_eq!(first_loss.to_bits(), second_loss.to_bits());
+        assert!(first_gradients.values().zip(second_gradients.values()).all(|(a, b)| a.to_bits() == b.to_bits()));
+    }
+}
@@ -0,0 +1,127 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use crate::data::preprocessing::MinMaxScaler;
+use super::{Layer, LossKind, NeuralNetwork, OptimizerKind, SchedulerKind, TrainingMetrics};
+
+/// Version courante du format JSON des modèles
+pub const MODEL_FORMAT_VERSION: u32 = 1;
+
+// Sérialisation du réseau complet (architecture, poids, entraînement, prétraitement)
+#[derive(Serialize, Deserialize)]
+struct NetworkData {
+    // Absent des fichiers antérieurs au versionnage (pipelines)
+    #[serde(default)]
+    format_version: u32,
+    layers: Vec<Layer>,
+    learning_rate: f64,
+    #[serde(default)]
+    metrics: TrainingMetrics,
+    #[serde(default)]
+    feature_names: Vec<String>,
+    #[serde(default)]
+    scaler: Option<MinMaxScaler>,
+    #[serde(default)]
+    optimizer: OptimizerKind,
+    #[serde(default)]
+    loss: LossKind,
+    #[serde(default)]
+    scheduler: Option<SchedulerKind>,
+}
+
+impl Serialize for NeuralNetwork {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let data = NetworkData {
+            format_version: MODEL_FORMAT_VERSION,
+            layers: self.layers.clone(),
+            learning_rate: self.learning_rate,
+            metrics: self.metrics.clone(),
+            feature_names: self.feature_names.clone(),
+            scaler: self.scaler.clone(),
+            optimizer: self.optimizer.clone(),
+            loss: self.loss.clone(),
+            scheduler: self.scheduler.clone(),
+        };
+        data.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for NeuralNetwork {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let data = NetworkData::deserialize(deserializer)?;
+
+        if data.format_version > MODEL_FORMAT_VERSION {
+            return Err(serde::de::Error::custom(format!(
+                "format de modèle {} plus récent que la version supportée ({})",
+                data.format_version, MODEL_FORMAT_VERSION
+            )));
+        }
+
+        let mut network = NeuralNetwork::new(data.learning_rate);
+        network.layers = data.layers;
+        network.metrics = data.metrics;
+        network.feature_names = data.feature_names;
+        network.scaler = data.scaler;
+        network.optimizer = data.optimizer;
+        network.loss = data.loss;
+        network.scheduler = data.scheduler;
+        Ok(network)
+    }
+}
+
+impl NeuralNetwork {
+    /// Sauvegarde le modèle complet en JSON
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(writer, self)?;
+        Ok(())
+    }
+
+    /// Recharge un modèle sauvegardé par `save`
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+}
+
+/// Flottants non finis (ex. `best_loss` initial = ∞) écrits `null`, JSON ne les acceptant pas
+pub(crate) mod non_finite {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+        if value.is_finite() {
+            serializer.serialize_some(value)
+        } else {
+            serializer.serialize_none()
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+        Ok(Option::<f64>::deserialize(deserializer)?.unwrap_or(f64::INFINITY))
+    }
+}
+
+/// Variante de `non_finite` pour les historiques; un `null` relu devient `NaN`, la valeur
+/// d'origine (NaN ou ±∞ après divergence) n'étant pas conservée
+pub(crate) mod non_finite_vec {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(values: &[f64], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(values.iter().map(|value| value.is_finite().then_some(*value)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<f64>, D::Error> {
+        Ok(Vec::<Option<f64>>::deserialize(deserializer)?
+            .into_iter()
+            .map(|value| value.unwrap_or(f64::NAN))
+            .collect())
+    }
+}
@@ -0,0 +1,96 @@
+use ndarray::{Array1, Array2, Axis};
+use serde::{Deserialize, Serialize};
+
+/// Régularisation d'une couche: pénalités L1/L2 (elastic-net) et contrainte max-norm
+///
+/// La pénalité vaut `l1·Σ|w| + ½·l2·Σw²`, ajoutée à la perte rapportée et dont le
+/// gradient est ajouté à celui de la perte avant chaque pas d'optimisation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Regularization {
+    pub l1: f64,
+    pub l2: f64,
+    /// Pénalise aussi les biais (exclus par défaut)
+    pub include_biases: bool,
+    /// Norme L2 maximale des poids entrants de chaque neurone, imposée après chaque pas
+    pub max_norm: Option<f64>,
+}
+
+impl Regularization {
+    /// Pénalité L1 (parcimonie)
+    pub fn l1(strength: f64) -> Self {
+        Self::elastic_net(strength, 0.0)
+    }
+
+    /// Pénalité L2 (weight decay)
+    pub fn l2(strength: f64) -> Self {
+        Self::elastic_net(0.0, strength)
+    }
+
+    /// Combinaison L1 + L2
+    pub fn elastic_net(l1: f64, l2: f64) -> Self {
+        Self { l1, l2, include_biases: false, max_norm: None }
+    }
+
+    /// Contrainte max-norm seule
+    pub fn max_norm(max_norm: f64) -> Self {
+        Self { max_norm: Some(max_norm), ..Self::elastic_net(0.0, 0.0) }
+    }
+
+    /// Étend les pénalités aux biais
+    pub fn with_biases(mut self) -> Self {
+        self.include_biases = true;
+        self
+    }
+
+    /// Ajoute une contrainte max-norm
+    pub fn with_max_norm(mut self, max_norm: f64) -> Self {
+        self.max_norm = Some(max_norm);
+        self
+    }
+
+    /// Pénalité pour les paramètres d'une couche
+    pub fn penalty(&self, weights: &Array2<f64>, biases: &Array1<f64>) -> f64 {
+        let term = |value: f64| self.l1 * value.abs() + 0.5 * self.l2 * value * value;
+        let mut penalty: f64 = weights.iter().map(|&w| term(w)).sum();
+        if self.include_biases {
+            penalty += biases.iter().map(|&b| term(b)).sum::<f64>();
+        }
+        penalty
+    }
+
+    /// Ajoute le gradient de la pénalité aux gradients d'une couche
+    pub fn add_gradients(
+        &self,
+        weights: &Array2<f64>,
+        biases: &Array1<f64>,
+        weight_gradients: &mut Array2<f64>,
+        bias_gradients: &mut Array1<f64>,
+    ) {
+        let gradient = |value: f64| self.l1 * sign(value) + self.l2 * value;
+        weight_gradients.zip_mut_with(weights, |g, &w| *g += gradient(w));
+        if self.include_biases {
+            bias_gradients.zip_mut_with(biases, |g, &b| *g += gradient(b));
+        }
+    }
+
+    /// Ramène chaque ligne de poids (un neurone) sur la boule de rayon `max_norm`
+    pub fn constrain(&self, weights: &mut Array2<f64>) {
+        let Some(max_norm) = self.max_norm else { return };
+        for mut row in weights.axis_iter_mut(Axis(0)) {
+            let norm = row.dot(&row).sqrt();
+            if norm > max_norm {
+                row *= max_norm / norm;
+            }
+        }
+    }
+}
+
+fn sign(value: f64) -> f64 {
+    if value > 0.0 {
+        1.0
+    } else if value < 0.0 {
+        -1.0
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,105 @@
+use ndarray::Array1;
+use super::{Activation, Gradients, Mode, NeuralNetwork, Trainer, TrainingConfig, TrainingMetrics, ValidationSet};
+
+/// Paramètres de la minimisation sensible à la netteté (SAM / ASAM)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SamConfig {
+    /// Rayon du voisinage dans lequel on cherche la pire perte
+    pub rho: f64,
+    /// ASAM: voisinage proportionnel à l'amplitude de chaque paramètre
+    pub adaptive: bool,
+    /// Plancher de l'échelle ASAM pour les paramètres proches de zéro
+    pub eta: f64,
+}
+
+impl SamConfig {
+    /// SAM classique (rayon 0.05)
+    pub fn sam() -> Self {
+        Self { rho: 0.05, adaptive: false, eta: 0.0 }
+    }
+
+    /// ASAM (rayon plus large, l'échelle étant relative aux poids)
+    pub fn asam() -> Self {
+        Self { rho: 0.5, adaptive: true, eta: 0.01 }
+    }
+}
+
+impl Default for SamConfig {
+    fn default() -> Self {
+        Self::sam()
+    }
+}
+
+impl NeuralNetwork {
+    /// ENTRAÎNEMENT SAM (minimisation sensible à la netteté)
+    ///
+    /// Boucle de `Trainer` à taux fixe dont chaque batch fait deux passes (voir
+    /// `TrainingConfig::sam`). La perte de validation (perte configurée par `set_loss`)
+    /// est suivie à chaque epoch quand un jeu de validation est fourni.
+    pub fn train_sam(
+        &mut self,
+        inputs: &[Array1<f64>],
+        targets: &[Array1<f64>],
+        validation: Option<ValidationSet>,
+        epochs: usize,
+        batch_size: usize,
+        config: &SamConfig,
+    ) -> TrainingMetrics {
+        let config = TrainingConfig { epochs, batch_size, sam: Some(config.clone()), ..TrainingConfig::default() };
+        Trainer::new(config).fit(self, inputs, targets, validation)
+    }
+
+    /// Gradients SAM d'un batch: montée vers θ + ε puis gradient mesuré en θ + ε
+    ///
+    /// Retourne la perte en θ et les gradients sommés en θ + ε, les paramètres étant
+    /// ramenés en θ. Les deux passes voient le même masque de dropout, le compteur de
+    /// tirages étant ramené à sa valeur d'avant la première. Les moyennes mobiles des
+    /// BatchNorm ne reçoivent que les statistiques de la passe en θ.
+    pub(super) fn sam_gradients<F>(
+        &mut self,
+        inputs: &[Array1<f64>],
+        targets: &[Array1<f64>],
+        batch_indices: &[usize],
+        config: &SamConfig,
+        objective: F,
+    ) -> (f64, Gradients)
+    where
+        F: Fn(usize, &Array1<f64>, &Array1<f64>, &Array1<f64>, &Activation) -> (f64, Array1<f64>) + Sync,
+    {
+        let draws = *self.dropout_draws.get_mut();
+        let (total_loss, mut gradients) =
+            self.compute_gradients(inputs, targets, batch_indices, Mode::Training, &objective);
+
+        let parameters = self.parameters();
+        let perturbed: Vec<f64> = parameters.iter()
+            .zip(self.sam_perturbation(&parameters, &gradients, config))
+            .map(|(theta, epsilon)| theta + epsilon)
+            .collect();
+        self.set_parameters(&perturbed);
+
+        *self.dropout_draws.get_mut() = draws;
+        let (_, mut perturbed_gradients) =
+            self.compute_gradients(inputs, targets, batch_indices, Mode::Training, &objective);
+        perturbed_gradients.batch_statistics = std::mem::take(&mut gradients.batch_statistics);
+
+        // Retour en θ avant que l'optimiseur applique le gradient mesuré en θ + ε
+        self.set_parameters(&parameters);
+
+        (total_loss, perturbed_gradients)
+    }
+
+    /// ε = ρ·T²g / ‖Tg‖, avec T = |θ| + η pour ASAM et T = 1 pour SAM
+    fn sam_perturbation(&self, parameters: &[f64], gradients: &Gradients, config: &SamConfig) -> Vec<f64> {
+        let scale = |param: f64| if config.adaptive { param.abs() + config.eta } else { 1.0 };
+
+        let norm = parameters.iter().zip(gradients.values())
+            .map(|(&theta, g)| (scale(theta) * g).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        let factor = config.rho / (norm + 1e-12);
+
+        parameters.iter().zip(gradients.values())
+            .map(|(&theta, g)| factor * g * scale(theta).powi(2))
+            .collect()
+    }
+}
@@ -0,0 +1,335 @@
+use std::f64::consts::PI;
+
+use serde::{Deserialize, Serialize};
+
+/// Planification du taux d'apprentissage, appelée à la fin de chaque epoch
+pub trait LrScheduler {
+    /// Taux d'apprentissage de la première epoch
+    fn initial_learning_rate(&self) -> f64;
+
+    /// Taux pour l'epoch suivante, à partir du taux courant et de la perte surveillée
+    /// (perte de validation si disponible, sinon perte d'entraînement)
+    fn step(&mut self, epoch: usize, current_lr: f64, monitored_loss: f64) -> f64;
+}
+
+/// Choix de planification sérialisable, instancié au début de chaque entraînement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SchedulerKind {
+    /// Taux fixe
+    Constant,
+    /// Multiplie le taux par `gamma` toutes les `step_size` epochs
+    Step { step_size: usize, gamma: f64 },
+    /// Décroissance exponentielle par phases: (epoch de début, facteur par epoch)
+    Exponential { phases: Vec<(usize, f64)>, min_lr: f64 },
+    /// Recuit cosinus avec redémarrages (SGDR)
+    CosineWarmRestarts { first_cycle: usize, cycle_mult: usize, min_lr: f64 },
+    /// Montée puis descente sur tout l'entraînement (super-convergence)
+    OneCycle { max_lr: f64, total_epochs: usize, warmup_fraction: f64 },
+    /// Montée linéaire jusqu'au taux de base, puis planification `after`
+    Warmup { epochs: usize, after: Box<SchedulerKind> },
+    /// Réduit le taux quand la perte surveillée stagne
+    ReduceOnPlateau { factor: f64, patience: usize, threshold: f64, cooldown: usize, min_lr: f64 },
+}
+
+impl SchedulerKind {
+    /// Décroissance exponentielle simple
+    pub fn exponential(gamma: f64) -> Self {
+        Self::Exponential { phases: vec![(0, gamma)], min_lr: 0.0 }
+    }
+
+    /// Réduction sur plateau avec les réglages usuels
+    pub fn reduce_on_plateau(factor: f64, patience: usize) -> Self {
+        Self::ReduceOnPlateau { factor, patience, threshold: 1e-4, cooldown: 0, min_lr: 1e-6 }
+    }
+
+    /// Montée linéaire suivie d'une autre planification
+    pub fn warmup(epochs: usize, after: SchedulerKind) -> Self {
+        Self::Warmup { epochs, after: Box::new(after) }
+    }
+
+    /// Décroissance agressive pour convergence rapide (`train_fast`)
+    pub fn aggressive() -> Self {
+        Self::Exponential { phases: vec![(0, 1.0), (10, 0.95), (30, 0.9)], min_lr: 1e-4 }
+    }
+
+    /// Décroissance conservatrice pour stabilité (`train_balanced`)
+    pub fn conservative() -> Self {
+        Self::Exponential { phases: vec![(0, 1.0), (30, 0.98), (100, 0.95)], min_lr: 1e-6 }
+    }
+
+    /// Décroissance progressive (`train_optimal`)
+    pub fn optimal() -> Self {
+        Self::Exponential { phases: vec![(0, 1.0), (21, 0.97), (61, 0.95), (91, 0.92)], min_lr: 1e-5 }
+    }
+
+    /// Crée la planification pour un taux de base
+    pub fn build(&self, base_lr: f64) -> Box<dyn LrScheduler> {
+        match self {
+            Self::Constant => Box::new(ConstantLr::new(base_lr)),
+            Self::Step { step_size, gamma } => Box::new(StepLr::new(base_lr, *step_size, *gamma)),
+            Self::Exponential { phases, min_lr } => Box::new(ExponentialLr::with_phases(base_lr, phases.clone(), *min_lr)),
+            Self::CosineWarmRestarts { first_cycle, cycle_mult, min_lr } => {
+                Box::new(CosineWarmRestarts::new(base_lr, *first_cycle, *cycle_mult, *min_lr))
+            }
+            Self::OneCycle { max_lr, total_epochs, warmup_fraction } => {
+                Box::new(OneCycle::new(*max_lr, *total_epochs, *warmup_fraction))
+            }
+            Self::Warmup { epochs, after } => Box::new(LinearWarmup::new(base_lr, *epochs, after.build(base_lr))),
+            Self::ReduceOnPlateau { factor, patience, threshold, cooldown, min_lr } => {
+                Box::new(ReduceOnPlateau::new(base_lr, *factor, *patience, *threshold, *cooldown, *min_lr))
+            }
+        }
+    }
+}
+
+/// Taux fixe
+pub struct ConstantLr {
+    pub base_lr: f64,
+}
+
+impl ConstantLr {
+    /// Crée une planification constante
+    pub fn new(base_lr: f64) -> Self {
+        Self { base_lr }
+    }
+}
+
+impl LrScheduler for ConstantLr {
+    fn initial_learning_rate(&self) -> f64 {
+        self.base_lr
+    }
+
+    fn step(&mut self, _epoch: usize, current_lr: f64, _monitored_loss: f64) -> f64 {
+        current_lr
+    }
+}
+
+/// Décroissance en escalier: base · γ^⌊(epoch + 1) / step_size⌋
+pub struct StepLr {
+    pub base_lr: f64,
+    pub step_size: usize,
+    pub gamma: f64,
+}
+
+impl StepLr {
+    /// Crée une décroissance en escalier
+    pub fn new(base_lr: f64, step_size: usize, gamma: f64) -> Self {
+        Self { base_lr, step_size: step_size.max(1), gamma }
+    }
+}
+
+impl LrScheduler for StepLr {
+    fn initial_learning_rate(&self) -> f64 {
+        self.base_lr
+    }
+
+    fn step(&mut self, epoch: usize, _current_lr: f64, _monitored_loss: f64) -> f64 {
+        self.base_lr * self.gamma.powi(((epoch + 1) / self.step_size) as i32)
+    }
+}
+
+/// Décroissance exponentielle, dont le facteur peut changer par phases
+pub struct ExponentialLr {
+    pub base_lr: f64,
+    /// (epoch de début, facteur appliqué à chaque epoch), triées par epoch
+    pub phases: Vec<(usize, f64)>,
+    pub min_lr: f64,
+}
+
+impl ExponentialLr {
+    /// Décroissance exponentielle de facteur `gamma`
+    pub fn new(base_lr: f64, gamma: f64) -> Self {
+        Self::with_phases(base_lr, vec![(0, gamma)], 0.0)
+    }
+
+    /// Décroissance dont le facteur change aux epochs indiquées
+    pub fn with_phases(base_lr: f64, mut phases: Vec<(usize, f64)>, min_lr: f64) -> Self {
+        phases.sort_by_key(|&(start, _)| start);
+        Self { base_lr, phases, min_lr }
+    }
+}
+
+impl LrScheduler for ExponentialLr {
+    fn initial_learning_rate(&self) -> f64 {
+        self.base_lr
+    }
+
+    fn step(&mut self, epoch: usize, current_lr: f64, _monitored_loss: f64) -> f64 {
+        let gamma = self.phases.iter()
+            .take_while(|&&(start, _)| start <= epoch)
+            .last()
+            .map_or(1.0, |&(_, gamma)| gamma);
+        (current_lr * gamma).max(self.min_lr)
+    }
+}
+
+/// Recuit cosinus avec redémarrages: chaque cycle est `cycle_mult` fois plus long
+pub struct CosineWarmRestarts {
+    pub base_lr: f64,
+    pub first_cycle: usize,
+    pub cycle_mult: usize,
+    pub min_lr: f64,
+}
+
+impl CosineWarmRestarts {
+    /// Crée un recuit cosinus avec redémarrages
+    pub fn new(base_lr: f64, first_cycle: usize, cycle_mult: usize, min_lr: f64) -> Self {
+        Self { base_lr, first_cycle: first_cycle.max(1), cycle_mult: cycle_mult.max(1), min_lr }
+    }
+}
+
+impl LrScheduler for CosineWarmRestarts {
+    fn initial_learning_rate(&self) -> f64 {
+        self.base_lr
+    }
+
+    fn step(&mut self, epoch: usize, _current_lr: f64, _monitored_loss: f64) -> f64 {
+        // Position de l'epoch suivante dans son cycle
+        let mut position = epoch + 1;
+        let mut cycle = self.first_cycle;
+        while position >= cycle {
+            position -= cycle;
+            cycle *= self.cycle_mult;
+        }
+
+        let progress = position as f64 / cycle as f64;
+        self.min_lr + (self.base_lr - self.min_lr) * (1.0 + (PI * progress).cos()) / 2.0
+    }
+}
+
+/// Politique one-cycle: montée cosinus de max/25 à max, puis descente jusqu'à max/10⁴
+pub struct OneCycle {
+    pub max_lr: f64,
+    pub total_epochs: usize,
+    pub warmup_fraction: f64,
+}
+
+impl OneCycle {
+    const DIV_FACTOR: f64 = 25.0;
+    const FINAL_DIV_FACTOR: f64 = 1e4;
+
+    /// Crée une politique one-cycle
+    pub fn new(max_lr: f64, total_epochs: usize, warmup_fraction: f64) -> Self {
+        Self { max_lr, total_epochs: total_epochs.max(2), warmup_fraction: warmup_fraction.clamp(0.0, 1.0) }
+    }
+
+    fn learning_rate_at(&self, epoch: usize) -> f64 {
+        let initial = self.max_lr / Self::DIV_FACTOR;
+        let last = (self.total_epochs - 1) as f64;
+        let peak = (self.warmup_fraction * last).round();
+        let epoch = (epoch as f64).min(last);
+
+        let anneal = |from: f64, to: f64, progress: f64| to + (from - to) * (1.0 + (PI * progress).cos()) / 2.0;
+        if epoch < peak {
+            anneal(initial, self.max_lr, epoch / peak)
+        } else {
+            let remaining = (last - peak).max(1.0);
+            anneal(self.max_lr, initial / Self::FINAL_DIV_FACTOR, (epoch - peak) / remaining)
+        }
+    }
+}
+
+impl LrScheduler for OneCycle {
+    fn initial_learning_rate(&self) -> f64 {
+        self.learning_rate_at(0)
+    }
+
+    fn step(&mut self, epoch: usize, _current_lr: f64, _monitored_loss: f64) -> f64 {
+        self.learning_rate_at(epoch + 1)
+    }
+}
+
+/// Montée linéaire jusqu'au taux de base, puis délégation à une autre planification
+pub struct LinearWarmup {
+    pub base_lr: f64,
+    pub epochs: usize,
+    after: Box<dyn LrScheduler>,
+}
+
+impl LinearWarmup {
+    /// Crée une montée linéaire sur `epochs` epochs
+    pub fn new(base_lr: f64, epochs: usize, after: Box<dyn LrScheduler>) -> Self {
+        Self { base_lr, epochs, after }
+    }
+}
+
+impl LrScheduler for LinearWarmup {
+    fn initial_learning_rate(&self) -> f64 {
+        if self.epochs == 0 {
+            self.after.initial_learning_rate()
+        } else {
+            self.base_lr / (self.epochs + 1) as f64
+        }
+    }
+
+    fn step(&mut self, epoch: usize, current_lr: f64, monitored_loss: f64) -> f64 {
+        let next = epoch + 1;
+        if next < self.epochs {
+            self.base_lr * (next + 1) as f64 / (self.epochs + 1) as f64
+        } else if next == self.epochs {
+            self.after.initial_learning_rate()
+        } else {
+            self.after.step(epoch - self.epochs, current_lr, monitored_loss)
+        }
+    }
+}
+
+/// Divise le taux par `1 / factor` après `patience` epochs sans amélioration
+pub struct ReduceOnPlateau {
+    pub base_lr: f64,
+    pub factor: f64,
+    pub patience: usize,
+    /// Amélioration relative minimale pour réinitialiser la patience
+    pub threshold: f64,
+    /// Epochs d'attente après chaque réduction
+    pub cooldown: usize,
+    pub min_lr: f64,
+    best: f64,
+    bad_epochs: usize,
+    cooldown_counter: usize,
+}
+
+impl ReduceOnPlateau {
+    /// Crée une réduction sur plateau
+    pub fn new(base_lr: f64, factor: f64, patience: usize, threshold: f64, cooldown: usize, min_lr: f64) -> Self {
+        Self {
+            base_lr,
+            factor,
+            patience,
+            threshold,
+            cooldown,
+            min_lr,
+            best: f64::INFINITY,
+            bad_epochs: 0,
+            cooldown_counter: 0,
+        }
+    }
+}
+
+impl LrScheduler for ReduceOnPlateau {
+    fn initial_learning_rate(&self) -> f64 {
+        self.base_lr
+    }
+
+    fn step(&mut self, _epoch: usize, current_lr: f64, monitored_loss: f64) -> f64 {
+        if monitored_loss < self.best * (1.0 - self.threshold) {
+            self.best = monitored_loss;
+            self.bad_epochs = 0;
+        } else {
+            self.bad_epochs += 1;
+        }
+
+        if self.cooldown_counter > 0 {
+            self.cooldown_counter -= 1;
+            self.bad_epochs = 0;
+        }
+
+        if self.bad_epochs > self.patience {
+            self.bad_epochs = 0;
+            self.cooldown_counter = self.cooldown;
+            return (current_lr * self.factor).max(self.min_lr);
+        }
+
+        current_lr
+    }
+}
@@ -0,0 +1,349 @@
+use ndarray::Array1;
+use super::callbacks::{BatchSummary, Callback, EpochSummary};
+use super::early_stopping::{BestEpoch, EarlyStopping, ValidationMetric};
+use super::{
+    Activation, Loss, LossKind, Mode, NeuralNetwork, Optimizer, OptimizerKind, SamConfig, SchedulerKind,
+    TrainingMetrics, ValidationSet,
+};
+
+/// Pondération des échantillons dans la perte et son gradient
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum SampleWeights {
+    #[default]
+    Uniform,
+    /// Cible binaire (positive si > 0.5), chaque classe pondérée par n / (2·n_classe)
+    ClassBalanced,
+    /// Un poids par échantillon, dans l'ordre des entrées
+    PerSample(Vec<f64>),
+    /// Perte doublée pour un positif prédit sous 0.3, gradient des positifs multiplié
+    /// par 1.5 (mode `train_balanced`)
+    FavorPositives,
+}
+
+/// Paramètres d'un entraînement par mini-batches
+#[derive(Debug, Clone)]
+pub struct TrainingConfig {
+    pub epochs: usize,
+    pub batch_size: usize,
+    /// Perte minimisée (`None`: celle choisie par `set_loss`)
+    pub loss: Option<LossKind>,
+    /// Optimiseur (`None`: celui choisi par `set_optimizer`)
+    pub optimizer: Option<OptimizerKind>,
+    /// Planification du taux, remplacée par celle de `set_scheduler` si le réseau en a une
+    pub scheduler: SchedulerKind,
+    /// Norme L2 maximale des gradients sommés sur le batch
+    pub gradient_clipping: Option<f64>,
+    /// Arrêt après plus de `patience` epochs sans amélioration de la perte d'entraînement
+    /// (sans arrêt précoce sur la validation)
+    pub patience: usize,
+    /// Arrêt sur une métrique de validation, à la place de `patience`, quand `fit` reçoit
+    /// un jeu de validation
+    pub early_stopping: Option<EarlyStopping>,
+    pub sample_weights: SampleWeights,
+    /// Minimisation sensible à la netteté: chaque batch fait deux passes, le gradient
+    /// appliqué étant mesuré au pire point du voisinage (voir `SamConfig`)
+    pub sam: Option<SamConfig>,
+    /// Une epoch affichée sur `log_interval`, en plus des améliorations
+    pub log_interval: usize,
+}
+
+impl TrainingConfig {
+    /// Décroissance agressive, arrêt rapide (`train_fast`)
+    pub fn fast() -> Self {
+        Self { scheduler: SchedulerKind::aggressive(), patience: 30, ..Self::default() }
+    }
+
+    /// Décroissance conservatrice, clipping à 2 et positifs favorisés (`train_balanced`)
+    pub fn balanced() -> Self {
+        Self {
+            scheduler: SchedulerKind::conservative(),
+            gradient_clipping: Some(2.0),
+            patience: 50,
+            sample_weights: SampleWeights::FavorPositives,
+            ..Self::default()
+        }
+    }
+
+    /// Entropie croisée binaire pondérée par classe, taux fixe (`train_with_class_weights`)
+    pub fn class_weighted() -> Self {
+        Self {
+            loss: Some(LossKind::bce()),
+            patience: 80,
+            sample_weights: SampleWeights::ClassBalanced,
+            log_interval: 30,
+            ..Self::default()
+        }
+    }
+
+    /// Décroissance progressive et clipping à 2.5 (`train_optimal`)
+    pub fn optimal() -> Self {
+        Self {
+            scheduler: SchedulerKind::optimal(),
+            gradient_clipping: Some(2.5),
+            patience: 35,
+            log_interval: 25,
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for TrainingConfig {
+    fn default() -> Self {
+        Self {
+            epochs: 100,
+            batch_size: 32,
+            loss: None,
+            optimizer: None,
+            scheduler: SchedulerKind::Constant,
+            gradient_clipping: None,
+            patience: 50,
+            early_stopping: None,
+            sample_weights: SampleWeights::Uniform,
+            sam: None,
+            log_interval: 20,
+        }
+    }
+}
+
+/// Boucle d'entraînement commune à tous les modes
+pub struct Trainer {
+    pub config: TrainingConfig,
+    callbacks: Vec<Box<dyn Callback>>,
+}
+
+impl Trainer {
+    /// Crée un entraîneur
+    pub fn new(config: TrainingConfig) -> Self {
+        Self { config, callbacks: Vec::new() }
+    }
+
+    /// Ajoute un callback, appelé après ceux déjà ajoutés
+    pub fn add_callback(&mut self, callback: impl Callback + 'static) -> &mut Self {
+        self.callbacks.push(Box::new(callback));
+        self
+    }
+
+    /// Entraîne `network` sur les données; les métriques s'ajoutent à celles du réseau
+    ///
+    /// Avec un jeu de validation, sa perte est enregistrée à chaque epoch et suivie par la
+    /// planification du taux; l'arrêt précoce configuré surveille alors sa métrique.
+    ///
+    /// Panique avant la première epoch si l'activation de sortie ne convient pas à la perte.
+    pub fn fit(
+        &mut self,
+        network: &mut NeuralNetwork,
+        inputs: &[Array1<f64>],
+        targets: &[Array1<f64>],
+        validation: Option<ValidationSet>,
+    ) -> TrainingMetrics {
+        let config = &self.config;
+        let mut scheduler = network.build_scheduler(config.scheduler.clone());
+        let optimizer_kind = config.optimizer.as_ref().unwrap_or(&network.optimizer);
+        let mut optimizer = optimizer_kind.build(scheduler.initial_learning_rate());
+        let loss_kind = config.loss.as_ref().unwrap_or(&network.loss);
+        if let Err(message) = network.check_loss_output(loss_kind) {
+            panic!("{}", message);
+        }
+        let loss = WeightedLoss {
+            loss: loss_kind.build(),
+            weights: config.sample_weights.resolve(targets),
+        };
+        let batch_size = config.batch_size.max(1);
+        let early_stopping = config.early_stopping.as_ref().filter(|_| validation.is_some());
+        let metric = early_stopping.map_or(ValidationMetric::Loss, |early_stopping| early_stopping.metric);
+        let mut best: Option<BestEpoch> = None;
+
+        println!("🎯 Entraînement - {} samples, batch: {}, epochs: {}", inputs.len(), batch_size, config.epochs);
+        if config.sample_weights == SampleWeights::ClassBalanced {
+            let (positive, negative) = class_weights(targets);
+            println!("   Poids: Parkinson={:.2}, Sain={:.2}", positive, negative);
+        }
+        if let Some(sam) = &config.sam {
+            println!("   {}: ρ = {}", if sam.adaptive { "ASAM" } else { "SAM" }, sam.rho);
+        }
+        if config.early_stopping.is_some() && validation.is_none() {
+            println!("⚠️  Arrêt précoce sans jeu de validation: patience sur la perte d'entraînement");
+        }
+
+        for callback in &mut self.callbacks {
+            callback.on_train_begin(network);
+        }
+
+        for epoch in 0..config.epochs {
+            let mut epoch_loss = 0.0;
+            let mut batches_processed = 0;
+
+            for callback in &mut self.callbacks {
+                callback.on_epoch_begin(epoch, network);
+            }
+
+            let mut indices: Vec<usize> = (0..inputs.len()).collect();
+            NeuralNetwork::shuffle_indices_fast(&mut indices);
+
+            for batch in indices.chunks(batch_size) {
+                let batch_loss = train_batch(config, network, inputs, targets, batch, optimizer.as_mut(), &loss);
+                let summary = BatchSummary { epoch, batch: batches_processed, size: batch.len(), loss: batch_loss };
+                for callback in &mut self.callbacks {
+                    callback.on_batch_end(&summary, network);
+                }
+                epoch_loss += batch_loss;
+                batches_processed += 1;
+            }
+
+            if batches_processed > 0 {
+                let avg_loss = epoch_loss / batches_processed as f64;
+
+                // Perte de validation et métrique surveillée
+                let scores = validation.map(|(val_inputs, val_targets)| {
+                    let (logits, outputs) = network.inference_batch(val_inputs);
+                    let validation_loss = ValidationMetric::Loss.evaluate(loss.loss.as_ref(), &logits, &outputs, val_targets);
+                    let monitored = match metric {
+                        ValidationMetric::Loss => validation_loss,
+                        metric => metric.evaluate(loss.loss.as_ref(), &logits, &outputs, val_targets),
+                    };
+                    (validation_loss, monitored)
+                });
+                if let Some((validation_loss, _)) = scores {
+                    network.metrics.validation_losses.push(validation_loss);
+                }
+
+                let monitored_loss = scores.map_or(avg_loss, |(validation_loss, _)| validation_loss);
+                optimizer.set_learning_rate(scheduler.step(epoch, optimizer.learning_rate(), monitored_loss));
+
+                let improved = network.metrics.update(avg_loss, 0.0, optimizer.learning_rate());
+
+                if epoch % config.log_interval.max(1) == 0 || epoch == config.epochs - 1 || improved {
+                    let marker = if improved { "📈" } else { "  " };
+                    match scores {
+                        Some((_, value)) => println!("Epoch {:3} {} Loss: {:.6} | Val {}: {:.6} | LR: {:.6}",
+                            epoch, marker, avg_loss, metric.name(), value, optimizer.learning_rate()),
+                        None => println!("Epoch {:3} {} Loss: {:.6} | LR: {:.6}", epoch, marker, avg_loss, optimizer.learning_rate()),
+                    }
+                }
+
+                let validation_stop = match (early_stopping, scores) {
+                    (Some(early_stopping), Some((_, value))) => {
+                        Some(BestEpoch::update(&mut best, early_stopping, epoch, value, &network.layers))
+                    }
+                    _ => None,
+                };
+
+                // Tous les callbacks voient l'epoch, même si l'un d'eux demande l'arrêt
+                let summary = EpochSummary {
+                    epoch,
+                    loss: avg_loss,
+                    validation: scores.map(|(_, value)| value),
+                    learning_rate: optimizer.learning_rate(),
+                    improved,
+                };
+                let mut stop = false;
+                for callback in &mut self.callbacks {
+                    stop |= callback.on_epoch_end(&summary, network).is_break();
+                }
+
+                if stop || validation_stop.unwrap_or(network.metrics.patience_counter > config.patience) {
+                    println!("⏹️  Arrêt à epoch {}", epoch);
+                    break;
+                }
+            }
+        }
+
+        if let (Some(early_stopping), Some(best)) = (early_stopping, best) {
+            println!("   Meilleure validation: {} = {:.6} (epoch {})", metric.name(), best.value, best.epoch);
+            if early_stopping.restore_best_weights {
+                network.layers = best.layers;
+                println!("   ↩️  Poids de l'epoch {} restaurés", best.epoch);
+            }
+        }
+
+        for callback in &mut self.callbacks {
+            callback.on_train_end(network);
+        }
+
+        println!("✅ Entraînement terminé! Best loss: {:.6}", network.metrics.best_loss);
+        network.metrics.clone()
+    }
+}
+
+/// Un pas d'optimisation sur un batch (deux passes avec SAM); retourne sa perte moyenne
+/// pénalité comprise
+fn train_batch(
+    config: &TrainingConfig,
+    network: &mut NeuralNetwork,
+    inputs: &[Array1<f64>],
+    targets: &[Array1<f64>],
+    batch: &[usize],
+    optimizer: &mut dyn Optimizer,
+    loss: &WeightedLoss,
+) -> f64 {
+    let objective = |index: usize, logits: &Array1<f64>, output: &Array1<f64>, target: &Array1<f64>, activation: &Activation| {
+        loss.evaluate(index, logits, output, target, activation)
+    };
+    let (total_loss, mut gradients) = match &config.sam {
+        Some(sam) => network.sam_gradients(inputs, targets, batch, sam, objective),
+        None => network.compute_gradients(inputs, targets, batch, Mode::Training, objective),
+    };
+
+    if let Some(max_norm) = config.gradient_clipping {
+        network.optimal_gradient_clipping(&mut gradients, max_norm);
+    }
+
+    let penalty = network.apply_gradients(optimizer, gradients, batch.len());
+
+    total_loss / batch.len() as f64 + penalty
+}
+
+impl SampleWeights {
+    /// Remplace la pondération par classe par les poids de chaque échantillon
+    fn resolve(&self, targets: &[Array1<f64>]) -> Self {
+        match self {
+            Self::ClassBalanced => {
+                let (positive, negative) = class_weights(targets);
+                Self::PerSample(targets.iter().map(|target| if target[0] > 0.5 { positive } else { negative }).collect())
+            }
+            weights => weights.clone(),
+        }
+    }
+}
+
+/// Perte et pondération des échantillons d'un entraînement
+struct WeightedLoss {
+    loss: Box<dyn Loss>,
+    weights: SampleWeights,
+}
+
+impl WeightedLoss {
+    /// Perte pondérée d'un échantillon et son gradient par rapport aux logits
+    fn evaluate(
+        &self,
+        index: usize,
+        logits: &Array1<f64>,
+        output: &Array1<f64>,
+        target: &Array1<f64>,
+        activation: &Activation,
+    ) -> (f64, Array1<f64>) {
+        let (value_weight, delta_weight) = match &self.weights {
+            SampleWeights::Uniform | SampleWeights::ClassBalanced => (1.0, 1.0),
+            SampleWeights::PerSample(weights) => {
+                let weight = weights.get(index).copied().unwrap_or(1.0);
+                (weight, weight)
+            }
+            SampleWeights::FavorPositives => {
+                // Double pénalité pour les faux négatifs, gradient renforcé pour les cas Parkinson
+                let positive = target[0] > 0.5;
+                (if positive && output[0] < 0.3 { 2.0 } else { 1.0 }, if positive { 1.5 } else { 1.0 })
+            }
+        };
+        let value = self.loss.value(logits, output, target) * value_weight;
+        let delta = self.loss.delta(logits, output, target, activation) * delta_weight;
+        (value, delta)
+    }
+}
+
+/// Poids des classes positive et négative: n / (2·n_classe), 1 pour une classe absente
+fn class_weights(targets: &[Array1<f64>]) -> (f64, f64) {
+    let positive = targets.iter().filter(|target| target[0] > 0.5).count();
+    let negative = targets.len() - positive;
+    let weight = |count: usize| if count > 0 { targets.len() as f64 / (2.0 * count as f64) } else { 1.0 };
+    (weight(positive), weight(negative))
+}
@@ -0,0 +1,98 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+
+use ndarray::Array1;
+use serde::{Deserialize, Serialize};
+
+use crate::data::preprocessing::Transformer;
+use crate::neural_network::{NeuralNetwork, TrainingMetrics};
+
+/// Chaîne de prétraitements ajustés suivie d'un réseau, sauvegardée d'un seul bloc
+#[derive(Serialize, Deserialize)]
+pub struct Pipeline {
+    pub steps: Vec<Transformer>,
+    pub network: NeuralNetwork,
+}
+
+impl Pipeline {
+    /// Crée un pipeline sans prétraitement autour d'un réseau
+    pub fn new(network: NeuralNetwork) -> Self {
+        Self {
+            steps: Vec::new(),
+            network,
+        }
+    }
+
+    /// Ajoute une étape de prétraitement (appliquée dans l'ordre d'ajout)
+    pub fn add_step(&mut self, step: Transformer) -> &mut Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Ajuste chaque étape sur les données d'entraînement puis entraîne le réseau
+    pub fn fit(
+        &mut self,
+        inputs: &[Array1<f64>],
+        targets: &[Array1<f64>],
+        epochs: usize,
+        batch_size: usize,
+    ) -> Result<TrainingMetrics, Box<dyn std::error::Error>> {
+        self.network.check_loss_output(self.network.loss())?;
+        let mut current = inputs.to_vec();
+        for step in &mut self.steps {
+            step.fit(&current, targets)?;
+            current = step.transform_all(&current, Some(targets))?;
+        }
+
+        let feature_count = current.first().map(|input| input.len()).unwrap_or(0);
+        if let Some(input_size) = self.network.input_size()
+            && input_size != feature_count
+        {
+            return Err(format!(
+                "le prétraitement produit {} features mais le réseau en attend {}",
+                feature_count, input_size
+            ).into());
+        }
+
+        Ok(self.network.train_optimal(&current, targets, epochs, batch_size))
+    }
+
+    /// Applique les prétraitements ajustés à un échantillon brut
+    pub fn transform(&self, input: &Array1<f64>) -> Result<Array1<f64>, Box<dyn std::error::Error>> {
+        let output = self.steps.iter()
+            .try_fold(input.clone(), |current, step| step.transform(&current))?;
+        if let Some(input_size) = self.network.input_size()
+            && input_size != output.len()
+        {
+            return Err(format!(
+                "le prétraitement produit {} features mais le réseau en attend {}",
+                output.len(), input_size
+            ).into());
+        }
+        Ok(output)
+    }
+
+    /// Prédiction sur un échantillon brut
+    pub fn predict(&self, input: &Array1<f64>) -> Result<Array1<f64>, Box<dyn std::error::Error>> {
+        Ok(self.network.forward(&self.transform(input)?))
+    }
+
+    /// Prédictions sur un ensemble d'échantillons bruts
+    pub fn predict_all(&self, inputs: &[Array1<f64>]) -> Result<Vec<Array1<f64>>, Box<dyn std::error::Error>> {
+        inputs.iter().map(|input| self.predict(input)).collect()
+    }
+
+    /// Sauvegarde le pipeline complet en JSON
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), Box<dyn std::error::Error>> {
+        let writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(writer, self)?;
+        Ok(())
+    }
+
+    /// Recharge un pipeline sauvegardé par `save`
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let reader = BufReader::new(File::open(path)?);
+        Ok(serde_json::from_reader(reader)?)
+    }
+}
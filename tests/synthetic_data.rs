@@ -0,0 +1,150 @@
+use std::path::PathBuf;
+
+use ndarray::Array1;
+use model_compilator::data::alignment::DatasetSource;
+use model_compilator::data::data_loader::ParkinsonDataset;
+use model_compilator::data::imputation::{ImputationStrategy, Imputer};
+use model_compilator::data::preprocessing::{MinMaxScaler, StandardScaler, Transformer};
+use model_compilator::data::split::{SplitEntry, SplitManifest};
+use model_compilator::data::synthetic::{SyntheticConfig, SyntheticGenerator};
+use model_compilator::neural_network::{Activation, LossKind, NeuralNetwork, NormalizationKind, Precision};
+use model_compilator::pipeline::Pipeline;
+
+/// Petit dataset synthétique tiré des lois ajustées sur les fichiers d'origine
+fn synthetic_dataset() -> ParkinsonDataset {
+    let source = ParkinsonDataset::load_raw().expect("fichiers parkinsons/ lisibles");
+    let config = SyntheticConfig {
+        classification_samples: 80,
+        regression_samples: 120,
+        classification_subjects: 16,
+        regression_subjects: 12,
+        seed: 7,
+        ..SyntheticConfig::default()
+    };
+    SyntheticGenerator::fit(&source).sample(&config).expect("lois ajustées")
+}
+
+/// Fichier temporaire propre au processus
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("model_compilator_{}_{}", std::process::id(), name))
+}
+
+fn classifier(input_size: usize) -> NeuralNetwork {
+    let mut network = NeuralNetwork::new(0.05);
+    network
+        .set_seed(3)
+        .add_layer(input_size, 8, Activation::prelu())
+        .add_normalization(NormalizationKind::batch())
+        .add_layer(8, 1, Activation::Sigmoid)
+        .set_loss(LossKind::bce());
+    network
+}
+
+fn assert_close(left: &[Array1<f64>], right: &[Array1<f64>], tolerance: f64) {
+    assert_eq!(left.len(), right.len());
+    for (a, b) in left.iter().zip(right) {
+        assert!((a - b).iter().all(|d| d.abs() <= tolerance), "{} au lieu de {}", b, a);
+    }
+}
+
+#[test]
+fn pipeline_predictions_survive_save_and_load() {
+    let mut dataset = synthetic_dataset();
+    for (i, input) in dataset.classification_inputs.iter_mut().enumerate().filter(|(i, _)| i % 9 == 0) {
+        input[i % 22] = f64::NAN;
+    }
+    let inputs = &dataset.classification_inputs;
+    let targets = &dataset.classification_targets;
+
+    let mut pipeline = Pipeline::new(classifier(22));
+    pipeline
+        .add_step(Transformer::Impute(Imputer::new(ImputationStrategy::Median)))
+        .add_step(Transformer::Standard(StandardScaler::new()));
+    pipeline.fit(inputs, targets, 5, 16).unwrap();
+    let predictions = pipeline.predict_all(inputs).unwrap();
+    assert!(predictions.iter().all(|prediction| prediction[0].is_finite()));
+
+    let path = temp_path("pipeline.json");
+    pipeline.save(&path).unwrap();
+    let loaded = Pipeline::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    // Sans la feature `float_roundtrip`, serde_json peut relire un flottant à 1 ulp près
+    assert_close(&predictions, &loaded.predict_all(inputs).unwrap(), 1e-12);
+    assert!(loaded.predict(&Array1::zeros(21)).is_err());
+    assert!(loaded.predict(&Array1::zeros(23)).is_err());
+}
+
+#[test]
+fn pipeline_rejects_an_output_the_loss_cannot_use() {
+    let dataset = synthetic_dataset();
+    let mut network = classifier(22);
+    network.set_loss(LossKind::CategoricalCrossEntropy);
+    let mut pipeline = Pipeline::new(network);
+    assert!(pipeline.fit(&dataset.classification_inputs, &dataset.classification_targets, 1, 16).is_err());
+}
+
+#[test]
+fn models_survive_json_and_binary_round_trips() {
+    let dataset = synthetic_dataset();
+    let inputs = &dataset.classification_inputs;
+    let mut network = classifier(22);
+    network.train_fast(inputs, &dataset.classification_targets, 5, 16);
+    let mut scaler = MinMaxScaler::new();
+    scaler.fit(inputs);
+    network.set_scaler(scaler).set_feature_names(&["a", "b"]);
+    let predictions: Vec<Array1<f64>> = inputs.iter().map(|input| network.forward(input)).collect();
+    let predict = |network: &NeuralNetwork| -> Vec<Array1<f64>> {
+        inputs.iter().map(|input| network.forward(input)).collect()
+    };
+
+    let path = temp_path("model.json");
+    network.save(&path).unwrap();
+    let from_json = NeuralNetwork::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_close(&predictions, &predict(&from_json), 1e-12);
+    assert_eq!(from_json.feature_names(), network.feature_names());
+
+    let bytes = network.to_bytes(Precision::F64);
+    let from_binary = NeuralNetwork::from_bytes(&bytes).unwrap();
+    assert_close(&predictions, &predict(&from_binary), 0.0);
+    assert_eq!(from_binary.scaler().unwrap().maxs, network.scaler().unwrap().maxs);
+
+    let compact = NeuralNetwork::from_bytes(&network.to_bytes(Precision::F32)).unwrap();
+    assert_close(&predictions, &predict(&compact), 1e-4);
+
+    let mut corrupted = bytes.clone();
+    corrupted[20] ^= 1;
+    assert!(NeuralNetwork::from_bytes(&corrupted).is_err());
+    assert!(NeuralNetwork::from_bytes(&bytes[..bytes.len() / 2]).is_err());
+}
+
+#[test]
+fn manifest_verify_rejects_leaked_rows() {
+    let dataset = synthetic_dataset();
+    let manifest = SplitManifest::by_subject(&dataset, DatasetSource::Regression, 0.2, 0.2, 5);
+    manifest.verify(&dataset).unwrap();
+
+    let leak = |mutate: &dyn Fn(&mut SplitManifest)| {
+        let mut leaked = manifest.clone();
+        mutate(&mut leaked);
+        leaked.verify(&dataset).is_err()
+    };
+    let first_test = manifest.test[0];
+    let first_validation = manifest.validation[0];
+    let first_train = manifest.train[0];
+
+    assert!(leak(&|m| m.train.push(first_test)));
+    assert!(leak(&|m| m.validation.push(first_train)));
+    assert!(leak(&|m| m.train.push(first_train)));
+    // Ligne déplacée mais sujet resté dans une autre partie
+    assert!(leak(&|m| {
+        m.validation.retain(|entry| *entry != first_validation);
+        m.train.push(first_validation);
+    }));
+    assert!(leak(&|m| m.test.push(SplitEntry { row: dataset.regression_inputs.len(), subject: 0 })));
+
+    let mut changed = dataset.clone();
+    changed.regression_targets[0][0] += 1.0;
+    assert!(manifest.verify(&changed).is_err());
+}